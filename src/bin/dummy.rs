@@ -1,16 +1,22 @@
 #[path = "../flatbuffer.rs"]
 mod flatbuffer;
+#[path = "../frame.rs"]
+mod frame;
+#[path = "../items.rs"]
+mod items;
+#[path = "../pattern.rs"]
+mod pattern;
+#[path = "../sanitize.rs"]
+mod sanitize;
 #[path = "../util.rs"]
 mod util;
 use flatbuffer::hex_flatbuffer::{
-    finish_messages_buffer, Messages, MessagesArgs, Packet, PacketArgs, PacketData, TryPut,
-    TryPutArgs,
+    finish_messages_buffer, Messages, MessagesArgs, Packet, PacketArgs, PacketData, TryHandshake,
+    TryHandshakeArgs, TryPut, TryPutArgs,
 };
 use flatbuffers::FlatBufferBuilder;
-use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt},
-    net::TcpStream,
-};
+use frame::{read_frame, write_frame, DEFAULT_MAX_FRAME_SIZE};
+use tokio::net::TcpStream;
 use util::*;
 
 //use base64::{engine::general_purpose::STANDARD as b64, Engine};
@@ -20,9 +26,44 @@ use std::env;
 
 use crate::flatbuffer::hex_flatbuffer::root_as_messages;
 
+/// asks the server which protocol version to use
+async fn handshake(tcp: &mut TcpStream) -> i16 {
+    let mut fbb = FlatBufferBuilder::new();
+    let thargs = TryHandshakeArgs {
+        versions: Some(fbb.create_vector(&[1i16])),
+    };
+    let pargs = PacketArgs {
+        data_type: PacketData::TryHandshake,
+        data: Some(TryHandshake::create(&mut fbb, &thargs).as_union_value()),
+    };
+    let pack = Packet::create(&mut fbb, &pargs);
+    let margs = MessagesArgs {
+        version: 0,
+        packets: Some(fbb.create_vector(&[pack])),
+    };
+    let msg = Messages::create(&mut fbb, &margs);
+    finish_messages_buffer(&mut fbb, msg);
+    write_frame(tcp, fbb.finished_data()).await.unwrap();
+    let buffer = read_frame(tcp, DEFAULT_MAX_FRAME_SIZE)
+        .await
+        .unwrap()
+        .expect("server closed the connection without responding");
+    let messages = root_as_messages(&buffer).unwrap();
+    println!("handshake {:?}", messages);
+    messages
+        .packets()
+        .and_then(|packets| packets.iter().find_map(|p| p.data_as_handshake_success()))
+        .expect("server did not accept our protocol version")
+        .version()
+}
+
 #[tokio::main]
 async fn main() {
     dotenv().ok();
+    let mut tcp = TcpStream::connect(env::var("URL").unwrap_or("127.0.0.1:8080".to_owned()))
+        .await
+        .unwrap();
+    let version = handshake(&mut tcp).await;
 
     let rand_iota = util::generate_random_iota();
 
@@ -33,10 +74,18 @@ async fn main() {
     }
 
     let mut fbb = FlatBufferBuilder::new();
-    let pat = generate_random_sig();
+    //the server turns away signatures that can't be drawn
+    let pat = loop {
+        let pat = generate_random_sig();
+        if pattern::validate(&pat).is_ok() {
+            break pat;
+        }
+    };
     let tpargs = TryPutArgs {
         pattern: Some(fbb.create_string(pat.as_str())),
         nbt: Some(fbb.create_vector(bytes.as_slice())),
+        ttl_seconds: 0,  //server default
+        namespace: None, //the shared global space
     };
 
     let pargs = PacketArgs {
@@ -45,23 +94,18 @@ async fn main() {
     };
     let pack = Packet::create(&mut fbb, &pargs);
     let margs = MessagesArgs {
-        version: 1,
+        version,
         packets: Some(fbb.create_vector(&[pack])),
     };
     let msg = Messages::create(&mut fbb, &margs);
     finish_messages_buffer(&mut fbb, msg);
-    let mut tcp = TcpStream::connect(env::var("URL").unwrap_or("127.0.0.1:8080".to_owned()))
-        .await
-        .unwrap();
     let buff = fbb.finished_data();
     println!("trying to send `{}` `{}`", pat, rand_iota);
-    let _ = tcp.write(buff).await.unwrap();
-    let mut buffer = vec![];
-    let mut sbuf = [0u8; 1024];
-    while root_as_messages(&buffer).is_err() {
-        let _ = tcp.read(&mut sbuf).await;
-        buffer.extend_from_slice(&sbuf);
-    }
+    write_frame(&mut tcp, buff).await.unwrap();
+    let buffer = read_frame(&mut tcp, DEFAULT_MAX_FRAME_SIZE)
+        .await
+        .unwrap()
+        .expect("server closed the connection without responding");
     println!("tcp {:?}", root_as_messages(&buffer));
     drop(tcp);
 }
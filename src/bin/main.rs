@@ -1,34 +1,66 @@
-#![feature(duration_constants)]
-
+#[path = "../config.rs"]
+mod config;
+#[path = "../error.rs"]
+mod error;
 #[path = "../flatbuffer.rs"]
 mod flatbuffer;
+#[path = "../frame.rs"]
+mod frame;
+#[path = "../futures.rs"]
+mod futures;
+#[path = "../items.rs"]
+mod items;
+#[path = "../pattern.rs"]
+mod pattern;
+#[path = "../sanitize.rs"]
+mod sanitize;
+#[path = "../storage/mod.rs"]
+mod storage;
 #[path = "../util.rs"]
 mod util;
 use flatbuffer::hex_flatbuffer::{
-    finish_messages_buffer, DeleteSuccess, DeleteSuccessArgs, ErrorResponse, ErrorResponseArgs,
-    FlatbufferMoment, GetSuccess, GetSuccessArgs, Packet, PacketArgs, PutSuccess, PutSuccessArgs,
+    finish_messages_buffer, CompleteFutureSuccess, CompleteFutureSuccessArgs, CreateFutureSuccess,
+    CreateFutureSuccessArgs, DeleteSuccess, DeleteSuccessArgs, ErrorResponse, ErrorResponseArgs,
+    ExistsSuccess, ExistsSuccessArgs, FetchFutureSuccess, FetchFutureSuccessArgs, FlatbufferMoment,
+    FutureHandle, FutureHandleArgs, GetSuccess, GetSuccessArgs, HandshakeSuccess,
+    HandshakeSuccessArgs, Iota, IotaArgs, ListSuccess, ListSuccessArgs, Packet, PacketArgs,
+    PollFutureSuccess, PollFutureSuccessArgs, PullItemSuccess, PullItemSuccessArgs,
+    PushItemSuccess, PushItemSuccessArgs, PutSuccess, PutSuccessArgs, RefreshSuccess,
+    RefreshSuccessArgs, ReplaceSuccess, ReplaceSuccessArgs, ReplacedType, ReplacedTypeArgs,
+    SanitizeReport, SanitizeReportArgs, TryCompleteFuture, TryCreateFuture, TryDelete, TryExists,
+    TryFetchFuture, TryGet, TryHandshake, TryList, TryPollFuture, TryPullItem, TryPushItem, TryPut,
+    TryRefresh, TryReplace,
 };
-use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Vector, WIPOffset};
 use quartz_nbt::io::{read_nbt, write_nbt, Flavor};
 use rand::Rng;
-use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt},
-    net::{TcpListener, TcpStream},
-    sync::Mutex,
-};
+use serde_json::{json, Value};
+use tokio::net::{TcpListener, TcpStream};
 //use base64::{engine::general_purpose::STANDARD as b64, Engine};
 use dotenv::dotenv;
 use once_cell::sync::OnceCell;
-use sqlx::{mysql::MySqlPool, query, MySql, Pool};
-use std::{env, net::SocketAddr, thread, time::Duration};
+use std::{env, net::SocketAddr, sync::Mutex, time::Duration};
 use tracing::{error, info, instrument, trace, warn};
 
 use crate::{
+    config::Config,
+    error::ErrorCode,
     flatbuffer::hex_flatbuffer::{root_as_messages, Messages, MessagesArgs, PacketData},
-    util::{sanatize_nbt, SanatizedNBTResult},
+    frame::{read_frame, write_frame, FrameError},
+    futures::{FutureError, Futures},
+    items::{is_resource_location, ItemRejection},
+    pattern::validate_angles,
+    sanitize::SanitizeError,
+    storage::{Storage, StorageError, StoredIota, StoredItem},
+    util::{check_limits, sanatize_nbt_counted, SanatizedNBTResult},
 };
 
-static DB_CONNECTION: OnceCell<Mutex<Pool<MySql>>> = OnceCell::new();
+/// every `Messages.version` this server can speak, oldest first
+const SUPPORTED_VERSIONS: [i16; 1] = [1];
+
+static CONFIG: OnceCell<Config> = OnceCell::new();
+static STORAGE: OnceCell<Box<dyn Storage>> = OnceCell::new();
+static FUTURES: OnceCell<Mutex<Futures>> = OnceCell::new();
 
 #[tokio::main]
 async fn main() {
@@ -42,33 +74,20 @@ async fn main() {
     info!("starting server!");
     //Setup of DB and other
     dotenv().ok();
+    CONFIG.set(Config::from_env()).unwrap();
+    FUTURES
+        .set(Mutex::new(Futures::new(CONFIG.get().unwrap().max_futures)))
+        .unwrap();
     let db_url =
         env::var("DATABASE_URL").expect("DATABASE_URL env var not set or in .env, please set it");
-    DB_CONNECTION
-        .set(Mutex::new(
-            MySqlPool::connect(&db_url)
+    STORAGE
+        .set(
+            storage::connect(&db_url)
                 .await
-                .unwrap_or_else(|_| panic!("failed to connect to db {}", db_url)),
-        ))
+                .unwrap_or_else(|e| panic!("failed to set up storage {}: {}", db_url, e)),
+        )
         .unwrap();
-    info!("db connected");
-    let con = DB_CONNECTION.get().unwrap().lock().await;
-
-    query!(
-        "
-    CREATE TABLE IF NOT EXISTS `HexDataStorage` (
-        Pattern VARCHAR(256) COMMENT 'the pattern to lookup db info' NOT NULL,
-        Data MEDIUMBLOB COMMENT 'the NBT data of the object' NOT NULL,
-        Password TINYBLOB COMMENT 'the key to delete this data' NOT NULL,
-        Deletion TIMESTAMP COMMENT 'The time when this data will be deleted' NOT NULL,
-        PRIMARY KEY (Pattern)
-    );"
-    )
-    .execute(&*con)
-    .await
-    .expect("tried to create db table (if it didn't exist), something went wrong");
-    drop(con);
-    info!("table setup");
+    info!("storage connected");
     let host_url = env::var("URL").unwrap_or("127.0.0.1:8080".to_owned());
     let tcp = TcpListener::bind(&host_url)
         .await
@@ -76,277 +95,1199 @@ async fn main() {
     info!("tcp binded");
     tokio::spawn(async move { prune_db().await });
 
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
     loop {
-        let (stream, addr) = tcp.accept().await.unwrap();
-        tokio::spawn(async move {
-            info!("spawn connection");
-            handle_conn(stream, addr).await;
-        });
+        tokio::select! {
+            accepted = tcp.accept() => {
+                let (stream, addr) = accepted.unwrap();
+                tokio::spawn(async move {
+                    info!("spawn connection");
+                    handle_conn(stream, addr).await;
+                });
+            }
+            _ = &mut shutdown => break,
+        }
     }
+    info!("shutting down");
+    STORAGE.get().unwrap().close().await;
+}
+
+/// Resolves on ctrl-c, or on SIGTERM where there is one.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut term = signal(SignalKind::terminate()).expect("failed to listen for SIGTERM");
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = term.recv() => {}
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = tokio::signal::ctrl_c().await;
 }
 
 #[instrument]
 async fn prune_db() {
     loop {
-        std::thread::sleep(Duration::from_secs(60 * 10)); //every 10 minutes we run a DB purge
+        tokio::time::sleep(Duration::from_secs(60 * 10)).await; //every 10 minutes we run a DB purge
         info!("running a prune");
-        let con = DB_CONNECTION.get().unwrap().lock().await;
-        match query!("DELETE FROM HexDataStorage WHERE Deletion < NOW()")
-            .execute(&*con)
-            .await
-        {
-            Ok(res) => info!("pruned DB {} rows affected", res.rows_affected()),
+        match STORAGE.get().unwrap().prune().await {
+            Ok(rows) => info!("pruned DB {} rows affected", rows),
             Err(err) => error!("failed the prune DB command: {}", err),
         }
-        drop(con);
+        let futures = FUTURES.get().unwrap().lock().unwrap().prune();
+        info!("freed {} expired futures", futures);
     }
 }
 
-fn make_err_packet(responses: &mut Vec<WIPOffset<Packet<'_>>>, id: u16, message: &str) {
-    let mut fbb = FlatBufferBuilder::new();
+/// `details` is merged into the JSON sent back, see [`ErrorCode::to_json`]
+fn make_err_packet<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+    code: ErrorCode,
+    details: Value,
+) {
     let err_datum = ErrorResponseArgs {
-        id,
-        other: Some(fbb.create_string(message)),
+        id: code.id(),
+        other: Some(fbb.create_string(&code.to_json(details))),
     };
-    let err_data = ErrorResponse::create(&mut fbb, &err_datum);
+    let err_data = ErrorResponse::create(fbb, &err_datum);
     let packet_data = PacketArgs {
         data_type: PacketData::ErrorResponse,
         data: Some(err_data.as_union_value()),
     };
-    let err_packet = Packet::create(&mut fbb, &packet_data);
+    let err_packet = Packet::create(fbb, &packet_data);
     responses.push(err_packet);
 }
 
-fn why_send_s2c_packets_to_server(responses: &mut Vec<WIPOffset<Packet<'_>>>) {
+fn why_send_s2c_packets_to_server<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
     info!("client sent client-bound packets to server");
-    make_err_packet(responses, 400, "do not send s2c packets to the server");
+    make_err_packet(
+        fbb,
+        responses,
+        ErrorCode::BadRequest,
+        json!({"reason": "do not send s2c packets to the server"}),
+    );
 }
 
-fn why_is_a_field_empty(responses: &mut Vec<WIPOffset<Packet<'_>>>) {
+fn why_is_a_field_empty<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
     warn!("some field in request is empty");
-    make_err_packet(responses, 400, "please make sure to fill all fields");
+    make_err_packet(fbb, responses, ErrorCode::MissingField, json!({}));
+}
+
+fn unsupported_version<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+    version: i16,
+) {
+    warn!("client asked for protocol version {}", version);
+    make_err_packet(
+        fbb,
+        responses,
+        ErrorCode::UnsupportedVersion,
+        json!({ "supported_versions": SUPPORTED_VERSIONS }),
+    );
+}
+
+/// finishes `responses` into a `Messages` and writes it out as one frame
+async fn send_responses<'a>(
+    stream: &mut TcpStream,
+    version: i16,
+    mut fbb: FlatBufferBuilder<'a>,
+    responses: Vec<WIPOffset<Packet<'a>>>,
+) {
+    let margs = MessagesArgs {
+        version,
+        packets: Some(fbb.create_vector(&responses)),
+    };
+    let message = Messages::create(&mut fbb, &margs);
+    finish_messages_buffer(&mut fbb, message);
+    if let Err(e) = write_frame(stream, fbb.finished_data()).await {
+        warn!("failed to send response: {}", e);
+    }
+}
+
+/// replies to a frame we couldn't make sense of with a single error
+async fn send_error(stream: &mut TcpStream, code: ErrorCode, details: Value) {
+    let mut fbb = FlatBufferBuilder::new();
+    let mut responses = vec![];
+    make_err_packet(&mut fbb, &mut responses, code, details);
+    send_responses(stream, 0, fbb, responses).await;
 }
 
 #[instrument(skip(stream))]
 async fn handle_conn(mut stream: TcpStream, saddr: SocketAddr) {
-    let mut buffer = vec![];
+    let max_frame_size = CONFIG.get().unwrap().max_frame_size;
     loop {
-        buffer.clear();
-        let mut sbuf = [0u8; 1024];
-        while root_as_messages(&buffer).is_err() {
-            let _ = stream.read(&mut sbuf).await;
-            buffer.extend_from_slice(&sbuf);
-        }
-        let messages = root_as_messages(&buffer).unwrap();
-        if messages.version() == 0 {
-            thread::sleep(Duration::MILLISECOND);
+        let buffer = match read_frame(&mut stream, max_frame_size).await {
+            Ok(Some(buffer)) => buffer,
+            Ok(None) => {
+                info!("client disconnected");
+                return;
+            }
+            Err(FrameError::TooLarge(len)) => {
+                //we can't skip the frame without reading all of it, so just drop the client
+                warn!("client sent a {} byte frame, closing connection", len);
+                send_error(
+                    &mut stream,
+                    ErrorCode::TooLarge,
+                    json!({ "max_frame_size": max_frame_size }),
+                )
+                .await;
+                return;
+            }
+            Err(err) => {
+                warn!("failed to read frame: {}", err);
+                return;
+            }
+        };
+        let messages = match root_as_messages(&buffer) {
+            Ok(messages) => messages,
+            Err(err) => {
+                warn!("frame was not a valid Messages: {}", err);
+                send_error(
+                    &mut stream,
+                    ErrorCode::BadRequest,
+                    json!({ "reason": err.to_string() }),
+                )
+                .await;
+                continue;
+            }
+        };
+        info!("valid packet recieved");
+        let version = messages.version();
+        let mut fbb = FlatBufferBuilder::new();
+        let mut responses: Vec<WIPOffset<Packet<'_>>> = vec![];
+        //the version the reply goes out as, a version we don't speak is refused in version 0
+        let reply_version = match (version, messages.packets()) {
+            (0, Some(packets)) => {
+                process_handshakes(packets, &mut fbb, &mut responses);
+                0
+            }
+            (0, None) => continue, //an empty version 0 message is just a keepalive
+            (1, Some(packets)) => {
+                process_v1(packets, &mut fbb, &mut responses).await;
+                1
+            }
+            (_, None) => {
+                warn!("why send a message if you aren't gonna send any packets!");
+                continue;
+            }
+            (other, Some(_)) => {
+                unsupported_version(&mut fbb, &mut responses, other);
+                0
+            }
+        };
+        if responses.is_empty() {
             continue;
         }
-        info!("valid packet recieved");
-        match messages.packets() {
-            Some(packets) => {
-                let mut fbb = FlatBufferBuilder::new();
-                let mut responses: Vec<WIPOffset<Packet<'_>>> = vec![];
-                info!("itterating over packets");
-                for packet in packets {
-                    info!("packet: {:?}", packet.data_type());
-                    match packet.data_type() {
-                        PacketData::DeleteSuccess => why_send_s2c_packets_to_server(&mut responses),
-                        PacketData::ErrorResponse => why_send_s2c_packets_to_server(&mut responses),
-                        PacketData::GetSuccess => why_send_s2c_packets_to_server(&mut responses),
-                        PacketData::PutSuccess => why_send_s2c_packets_to_server(&mut responses),
-                        PacketData::TryDelete => {
-                            let td = packet.data_as_try_delete().unwrap();
-                            match td.password() {
-                                None => why_is_a_field_empty(&mut responses),
-                                Some(password) => match td.pattern() {
-                                    None => why_is_a_field_empty(&mut responses),
-                                    Some(pattern) => {
-                                        trace!("sanatizing pattern");
-                                        let pat: String = pattern
-                                            .chars()
-                                            .filter(|c| "qweasd".contains(*c))
-                                            .collect();
-                                        trace!("locking db");
-                                        let con = DB_CONNECTION.get().unwrap().lock().await;
-                                        trace!("locked db");
-                                        let res = query!("DELETE FROM HexDataStorage WHERE Pattern = ? AND Password = ?;",
-                                                pat,&password.0[..]
-                                            ).execute(&*con).await;
-                                        drop(con);
-                                        trace!("unlocked db");
-                                        match res {
-                                            Ok(_res) => {
-                                                trace!("create packet");
-                                                let dsa = DeleteSuccessArgs::default();
-                                                let packet_args = PacketArgs {
-                                                    data_type: PacketData::DeleteSuccess,
-                                                    data: Some(
-                                                        DeleteSuccess::create(&mut fbb, &dsa)
-                                                            .as_union_value(),
-                                                    ),
-                                                };
-                                                responses
-                                                    .push(Packet::create(&mut fbb, &packet_args));
-                                            }
-                                            Err(ohno) => make_err_packet(
-                                                &mut responses,
-                                                500,
-                                                ohno.to_string().as_str(),
-                                            ),
-                                        }
-                                    }
-                                },
-                            }
-                        }
-                        PacketData::TryGet => {
-                            let tg_packet = packet.data_as_try_get().unwrap();
-                            match tg_packet.pattern() {
-                                None => why_is_a_field_empty(&mut responses),
-                                Some(pattern) => {
-                                    trace!("locking db");
-                                    let con = DB_CONNECTION.get().unwrap().lock().await;
-                                    trace!("locked db");
-                                    let q = query!(
-                                        "SELECT Data FROM HexDataStorage WHERE Pattern = ?;",
-                                        pattern
-                                    )
-                                    .fetch_one(&*con)
-                                    .await;
-                                    drop(con);
-                                    trace!("unlocked db");
-                                    match q {
-                                        Ok(res) => {
-                                            trace!("creating packet");
-                                            let gsargs = GetSuccessArgs {
-                                                nbt: Some(fbb.create_vector(&res.Data)),
-                                            };
-                                            let pargs = PacketArgs {
-                                                data_type: PacketData::GetSuccess,
-                                                data: Some(
-                                                    GetSuccess::create(&mut fbb, &gsargs)
-                                                        .as_union_value(),
-                                                ),
-                                            };
-                                            responses.push(Packet::create(&mut fbb, &pargs));
-                                        }
-                                        Err(ohno) => make_err_packet(
-                                            &mut responses,
-                                            500,
-                                            ohno.to_string().as_str(),
-                                        ),
-                                    };
-                                }
-                            }
-                        }
-                        PacketData::TryPut => {
-                            let tp = packet.data_as_try_put().unwrap();
-                            match tp.nbt() {
-                                None => why_is_a_field_empty(&mut responses),
-                                Some(nbt) => match tp.pattern() {
-                                    None => why_is_a_field_empty(&mut responses),
-                                    Some(pat) => {
-                                        trace!("sanatizing iota");
-                                        let mut nbytes = nbt.bytes();
-                                        let nbt = read_nbt(&mut nbytes, Flavor::Uncompressed);
-                                        if let Err(ono) = nbt {
-                                            warn!("nbt was invalid");
-                                            make_err_packet(
-                                                &mut responses,
-                                                400,
-                                                ono.to_string().as_str(),
-                                            );
-                                            continue;
-                                        }
-                                        let SanatizedNBTResult {
-                                            consumed_entity,
-                                            resultant_compound,
-                                        } = sanatize_nbt(nbt.unwrap().0);
-                                        let mut ser_nbt = vec![];
-                                        if let Err(e) = write_nbt(
-                                            &mut ser_nbt,
-                                            None,
-                                            &resultant_compound,
-                                            Flavor::Uncompressed,
-                                        ) {
-                                            error!("failed to seralize nbt post-seralization");
-                                            make_err_packet(
-                                                &mut responses,
-                                                500,
-                                                format!(
-                                                    "failure while re-seralizing sanatized nbt: {}",
-                                                    e.to_string()
-                                                )
-                                                .as_str(),
-                                            )
-                                        };
-                                        trace!("stripping pattern");
-                                        let pat = pat
-                                            .chars()
-                                            .filter(|c| "qweasd".contains(*c))
-                                            .collect::<String>();
-                                        trace!("generating password");
-                                        let mut password = [0u8; 255];
-                                        {
-                                            let mut rng = rand::thread_rng();
-                                            rng.fill(&mut password);
-                                        }
-                                        trace!("obtaining db lock");
-                                        let con = DB_CONNECTION.get().unwrap().lock().await;
-                                        trace!("db locked");
-                                        let q = query!("INSERT INTO HexDataStorage (Pattern, Data, Password, Deletion) VALUES (?,?,?,?)",
-                                                pat,ser_nbt,&password[..],time::OffsetDateTime::now_utc() + time::Duration::HOUR
-                                            ).execute(&*con).await;
-                                        drop(con);
-                                        trace!("unlocked db");
-                                        match q {
-                                            Ok(_resp) => {
-                                                trace!("creating packet");
-                                                let fbmoment = FlatbufferMoment::new(&password);
-                                                let psargs = PutSuccessArgs {
-                                                    password: Some(&fbmoment),
-                                                    sanatized_entity: consumed_entity,
-                                                };
-                                                let pargs = PacketArgs {
-                                                    data_type: PacketData::PutSuccess,
-                                                    data: Some(
-                                                        PutSuccess::create(&mut fbb, &psargs)
-                                                            .as_union_value(),
-                                                    ),
-                                                };
-                                                responses.push(Packet::create(&mut fbb, &pargs));
-                                            }
-                                            Err(ohno) => make_err_packet(
-                                                &mut responses,
-                                                500,
-                                                &ohno.to_string(),
-                                            ),
-                                        }
-                                    }
-                                },
-                            }
-                        }
-                        PacketData::NONE => why_is_a_field_empty(&mut responses),
-                        flatbuffer::hex_flatbuffer::PacketData(8_u8..=u8::MAX) => {
-                            warn!("client is sending packet types that dont exist, be very afraid");
-                            make_err_packet(&mut responses, 400, "request type not supported")
-                        }
+        info!("finished processing packets, sending to client");
+        send_responses(&mut stream, reply_version, fbb, responses).await;
+    }
+}
+
+/// version 0 messages don't have a protocol yet, so only handshakes get answered
+fn process_handshakes<'a>(
+    packets: Vector<'_, ForwardsUOffset<Packet<'_>>>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    for packet in packets {
+        match packet.data_as_try_handshake() {
+            Some(th) => handle_try_handshake(th, fbb, responses),
+            None => trace!("ignoring {:?} in a version 0 message", packet.data_type()),
+        }
+    }
+}
+
+async fn process_v1<'a>(
+    packets: Vector<'_, ForwardsUOffset<Packet<'_>>>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    info!("itterating over packets");
+    for packet in packets {
+        info!("packet: {:?}", packet.data_type());
+        match packet.data_type() {
+            PacketData::DeleteSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::ErrorResponse => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::GetSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::PutSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::HandshakeSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::ReplaceSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::RefreshSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::CreateFutureSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::CompleteFutureSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::PollFutureSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::FetchFutureSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::PushItemSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::PullItemSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::ListSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::ExistsSuccess => why_send_s2c_packets_to_server(fbb, responses),
+            PacketData::TryDelete => {
+                handle_try_delete(packet.data_as_try_delete().unwrap(), fbb, responses).await
+            }
+            PacketData::TryGet => {
+                handle_try_get(packet.data_as_try_get().unwrap(), fbb, responses).await
+            }
+            PacketData::TryPut => {
+                handle_try_put(packet.data_as_try_put().unwrap(), fbb, responses).await
+            }
+            PacketData::TryReplace => {
+                handle_try_replace(packet.data_as_try_replace().unwrap(), fbb, responses).await
+            }
+            PacketData::TryRefresh => {
+                handle_try_refresh(packet.data_as_try_refresh().unwrap(), fbb, responses).await
+            }
+            PacketData::TryCreateFuture => handle_try_create_future(
+                packet.data_as_try_create_future().unwrap(),
+                fbb,
+                responses,
+            ),
+            PacketData::TryCompleteFuture => handle_try_complete_future(
+                packet.data_as_try_complete_future().unwrap(),
+                fbb,
+                responses,
+            ),
+            PacketData::TryPollFuture => {
+                handle_try_poll_future(packet.data_as_try_poll_future().unwrap(), fbb, responses)
+            }
+            PacketData::TryFetchFuture => {
+                handle_try_fetch_future(packet.data_as_try_fetch_future().unwrap(), fbb, responses)
+            }
+            PacketData::TryPushItem => {
+                handle_try_push_item(packet.data_as_try_push_item().unwrap(), fbb, responses).await
+            }
+            PacketData::TryPullItem => {
+                handle_try_pull_item(packet.data_as_try_pull_item().unwrap(), fbb, responses).await
+            }
+            PacketData::TryExists => {
+                handle_try_exists(packet.data_as_try_exists().unwrap(), fbb, responses).await
+            }
+            PacketData::TryList => {
+                handle_try_list(packet.data_as_try_list().unwrap(), fbb, responses).await
+            }
+            PacketData::TryHandshake => {
+                handle_try_handshake(packet.data_as_try_handshake().unwrap(), fbb, responses)
+            }
+            PacketData::NONE => why_is_a_field_empty(fbb, responses),
+            flatbuffer::hex_flatbuffer::PacketData(30_u8..=u8::MAX) => {
+                warn!("client is sending packet types that dont exist, be very afraid");
+                make_err_packet(
+                    fbb,
+                    responses,
+                    ErrorCode::BadRequest,
+                    json!({"reason": "request type not supported"}),
+                )
+            }
+        }
+    }
+}
+
+fn handle_try_handshake<'a>(
+    th: TryHandshake<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let Some(versions) = th.versions() else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    //pick the newest version both sides know
+    let picked = SUPPORTED_VERSIONS
+        .iter()
+        .rev()
+        .find(|v| versions.iter().any(|cv| cv == **v));
+    match picked {
+        Some(&version) => {
+            trace!("negotiated protocol version {}", version);
+            let hsargs = HandshakeSuccessArgs {
+                version,
+                supported_versions: Some(fbb.create_vector(&SUPPORTED_VERSIONS)),
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::HandshakeSuccess,
+                data: Some(HandshakeSuccess::create(fbb, &hsargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        None => unsupported_version(fbb, responses, versions.iter().max().unwrap_or(0)),
+    }
+}
+
+async fn handle_try_delete<'a>(
+    td: TryDelete<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    match td.password() {
+        None => why_is_a_field_empty(fbb, responses),
+        Some(password) => match td.pattern() {
+            None => why_is_a_field_empty(fbb, responses),
+            Some(pattern) => {
+                let Some(pat) = pattern_key(pattern, td.namespace(), fbb, responses) else {
+                    return;
+                };
+                trace!("deleting from storage");
+                let res = STORAGE.get().unwrap().delete(&pat, &password.0[..]).await;
+                match res {
+                    Ok(()) => {
+                        trace!("create packet");
+                        let dsa = DeleteSuccessArgs::default();
+                        let packet_args = PacketArgs {
+                            data_type: PacketData::DeleteSuccess,
+                            data: Some(DeleteSuccess::create(fbb, &dsa).as_union_value()),
+                        };
+                        responses.push(Packet::create(fbb, &packet_args));
+                    }
+                    Err(StorageError::NotFound(_)) => make_err_packet(
+                        fbb,
+                        responses,
+                        ErrorCode::NotFound,
+                        json!({ "pattern": pattern }),
+                    ),
+                    Err(StorageError::WrongPassword(_)) => make_err_packet(
+                        fbb,
+                        responses,
+                        ErrorCode::WrongPassword,
+                        json!({ "pattern": pattern }),
+                    ),
+                    Err(ohno) => {
+                        error!("failed to delete from storage: {}", ohno);
+                        make_err_packet(fbb, responses, ErrorCode::Internal, json!({}))
                     }
                 }
-                info!("finished processing packets");
-                let margs = MessagesArgs {
-                    version: 1,
-                    packets: Some(fbb.create_vector(&responses)),
+            }
+        },
+    }
+}
+
+async fn handle_try_get<'a>(
+    tg_packet: TryGet<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    match tg_packet.pattern() {
+        None => why_is_a_field_empty(fbb, responses),
+        Some(pattern) => {
+            let Some(pat) = pattern_key(pattern, tg_packet.namespace(), fbb, responses) else {
+                return;
+            };
+            trace!("reading from storage");
+            let q = STORAGE.get().unwrap().get(&pat).await;
+            match q {
+                Ok(data) => {
+                    trace!("creating packet");
+                    let gsargs = GetSuccessArgs {
+                        nbt: Some(fbb.create_vector(&data)),
+                    };
+                    let pargs = PacketArgs {
+                        data_type: PacketData::GetSuccess,
+                        data: Some(GetSuccess::create(fbb, &gsargs).as_union_value()),
+                    };
+                    responses.push(Packet::create(fbb, &pargs));
+                }
+                Err(StorageError::NotFound(_)) => make_err_packet(
+                    fbb,
+                    responses,
+                    ErrorCode::NotFound,
+                    json!({ "pattern": pattern }),
+                ),
+                Err(ohno) => {
+                    error!("failed to read from storage: {}", ohno);
+                    make_err_packet(fbb, responses, ErrorCode::Internal, json!({}))
+                }
+            };
+        }
+    }
+}
+
+async fn handle_try_exists<'a>(
+    te: TryExists<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let Some(pattern) = te.pattern() else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    let Some(pat) = pattern_key(pattern, te.namespace(), fbb, responses) else {
+        return;
+    };
+    trace!("reading metadata from storage");
+    let esargs = match STORAGE.get().unwrap().stat(&pat).await {
+        Ok(stat) => {
+            let ttl = stat.deletion - time::OffsetDateTime::now_utc();
+            ExistsSuccessArgs {
+                exists: true,
+                expiry: stat.deletion.unix_timestamp(),
+                ttl_seconds: ttl.whole_seconds().max(0),
+                size: stat.size,
+                iota_type: Some(fbb.create_string(&stat.iota_type)),
+                sanatized_entity: stat.sanitized,
+            }
+        }
+        Err(StorageError::NotFound(_)) => ExistsSuccessArgs {
+            exists: false,
+            ..Default::default()
+        },
+        Err(ohno) => {
+            error!("failed to read metadata from storage: {}", ohno);
+            return make_err_packet(fbb, responses, ErrorCode::Internal, json!({}));
+        }
+    };
+    let pargs = PacketArgs {
+        data_type: PacketData::ExistsSuccess,
+        data: Some(ExistsSuccess::create(fbb, &esargs).as_union_value()),
+    };
+    responses.push(Packet::create(fbb, &pargs));
+}
+
+async fn handle_try_list<'a>(
+    tl: TryList<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let config = CONFIG.get().unwrap();
+    let prefix = tl.prefix().unwrap_or_default();
+    //a prefix doesn't have to be drawable on its own, it only has to be made of angles
+    let key_prefix = match validate_angles(prefix)
+        .and_then(|_| config.patterns.key_prefix(tl.namespace()))
+    {
+        Ok(key_prefix) => key_prefix,
+        Err(e) => {
+            warn!(
+                "invalid list prefix {:?} in {:?}: {}",
+                prefix,
+                tl.namespace(),
+                e
+            );
+            return make_err_packet(
+                fbb,
+                responses,
+                ErrorCode::InvalidPattern,
+                json!({ "pattern": prefix, "namespace": tl.namespace(), "reason": e.to_string() }),
+            );
+        }
+    };
+    let limit = match tl.limit() {
+        0 => config.max_list_page,
+        limit => limit.min(config.max_list_page),
+    };
+    let after = format!("{}{}", key_prefix, tl.cursor().unwrap_or_default());
+    let prefix = format!("{}{}", key_prefix, prefix);
+    trace!("listing patterns under {:?} after {:?}", prefix, after);
+    //ask for one more than the page holds to know whether there is a next page
+    let q = STORAGE
+        .get()
+        .unwrap()
+        .list_patterns(&prefix, &after, limit.saturating_add(1))
+        .await;
+    match q {
+        Ok(mut keys) => {
+            let more = keys.len() > limit as usize;
+            keys.truncate(limit as usize);
+            let patterns = keys
+                .iter()
+                .map(|key| key[key_prefix.len()..].to_owned())
+                .collect::<Vec<_>>();
+            let next_cursor = match patterns.last() {
+                Some(last) if more => Some(fbb.create_string(last)),
+                _ => None,
+            };
+            let lsargs = ListSuccessArgs {
+                patterns: Some(create_strings(fbb, &patterns)),
+                next_cursor,
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::ListSuccess,
+                data: Some(ListSuccess::create(fbb, &lsargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(ohno) => {
+            error!("failed to list patterns in storage: {}", ohno);
+            make_err_packet(fbb, responses, ErrorCode::Internal, json!({}))
+        }
+    }
+}
+
+/// validates the pattern (and namespace) of a packet and turns it into its storage key, sending an error and returning `None` if it is invalid
+fn pattern_key<'a>(
+    pattern: &str,
+    namespace: Option<&str>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) -> Option<String> {
+    match CONFIG
+        .get()
+        .unwrap()
+        .patterns
+        .storage_key(pattern, namespace)
+    {
+        Ok(key) => Some(key),
+        Err(e) => {
+            warn!("invalid pattern {:?} in {:?}: {}", pattern, namespace, e);
+            make_err_packet(
+                fbb,
+                responses,
+                ErrorCode::InvalidPattern,
+                json!({ "pattern": pattern, "namespace": namespace, "reason": e.to_string() }),
+            );
+            None
+        }
+    }
+}
+
+/// reads and sanatizes the NBT of a put or replace, sending an error and returning `None` if that fails
+///
+/// `iotas` counts the iotas seen so far, for when several of them share the `max_iotas` limit
+fn prepare_nbt<'a>(
+    nbt: &[u8],
+    iotas: &mut usize,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) -> Option<(Vec<u8>, SanatizedNBTResult)> {
+    trace!("sanatizing iota");
+    let policy = &CONFIG.get().unwrap().sanitize;
+    if let Err(e) = check_limits(nbt, &policy.limits) {
+        refuse_iota(fbb, responses, e);
+        return None;
+    }
+    let mut nbytes = nbt;
+    let nbt = read_nbt(&mut nbytes, Flavor::Uncompressed);
+    if let Err(ono) = nbt {
+        warn!("nbt was invalid");
+        make_err_packet(
+            fbb,
+            responses,
+            ErrorCode::InvalidNbt,
+            json!({ "reason": ono.to_string() }),
+        );
+        return None;
+    }
+    let san = match sanatize_nbt_counted(nbt.unwrap().0, policy, iotas) {
+        Ok(san) => san,
+        Err(e) => {
+            refuse_iota(fbb, responses, e);
+            return None;
+        }
+    };
+    if !san.stripped_items.is_empty() {
+        trace!("stripped nbt from {:?}", san.stripped_items);
+    }
+    let mut ser_nbt = vec![];
+    if let Err(e) = write_nbt(
+        &mut ser_nbt,
+        None,
+        &san.resultant_compound,
+        Flavor::Uncompressed,
+    ) {
+        error!("failed to seralize nbt post-seralization: {}", e);
+        make_err_packet(fbb, responses, ErrorCode::Internal, json!({}));
+        return None;
+    };
+    Some((ser_nbt, san))
+}
+
+fn refuse_iota<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+    e: SanitizeError,
+) {
+    warn!("refused iota: {}", e);
+    let code = match e {
+        SanitizeError::Oversized { .. }
+        | SanitizeError::TooDeep { .. }
+        | SanitizeError::TooManyIotas { .. } => ErrorCode::IotaTooLarge,
+        SanitizeError::UnknownType { .. } => ErrorCode::UnknownIotaType,
+        SanitizeError::NotAnIota { .. }
+        | SanitizeError::MissingData { .. }
+        | SanitizeError::WrongShape { .. } => ErrorCode::BadRequest,
+    };
+    make_err_packet(fbb, responses, code, e.to_json());
+}
+
+fn create_report<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    san: &SanatizedNBTResult,
+) -> WIPOffset<SanitizeReport<'a>> {
+    let replaced = san
+        .replaced
+        .iter()
+        .map(|(iota_type, &count)| {
+            let rtargs = ReplacedTypeArgs {
+                iota_type: Some(fbb.create_string(iota_type)),
+                count,
+            };
+            ReplacedType::create(fbb, &rtargs)
+        })
+        .collect::<Vec<_>>();
+    let unknown_types = san.unknown_types.iter().cloned().collect::<Vec<_>>();
+    let srargs = SanitizeReportArgs {
+        replaced: Some(fbb.create_vector(&replaced)),
+        replaced_paths: Some(create_strings(fbb, &san.replaced_paths)),
+        passed_unknown: !unknown_types.is_empty(),
+        unknown_types: Some(create_strings(fbb, &unknown_types)),
+    };
+    SanitizeReport::create(fbb, &srargs)
+}
+
+fn create_strings<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    strings: &[String],
+) -> WIPOffset<Vector<'a, ForwardsUOffset<&'a str>>> {
+    let strings = strings
+        .iter()
+        .map(|s| fbb.create_string(s))
+        .collect::<Vec<_>>();
+    fbb.create_vector(&strings)
+}
+
+async fn handle_try_put<'a>(
+    tp: TryPut<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    match tp.nbt() {
+        None => why_is_a_field_empty(fbb, responses),
+        Some(nbt) => match tp.pattern() {
+            None => why_is_a_field_empty(fbb, responses),
+            Some(pattern) => {
+                let Some((ser_nbt, san)) = prepare_nbt(nbt.bytes(), &mut 0, fbb, responses) else {
+                    return;
+                };
+                let Some(pat) = pattern_key(pattern, tp.namespace(), fbb, responses) else {
+                    return;
                 };
-                let message = Messages::create(&mut fbb, &margs);
-                finish_messages_buffer(&mut fbb, message);
-                let finished = fbb.finished_data();
-                info!("packet finalized, sending to client");
-                let _ = stream.write(finished).await;
+                trace!("generating password");
+                let mut password = [0u8; 255];
+                {
+                    let mut rng = rand::thread_rng();
+                    rng.fill(&mut password);
+                }
+                let deletion =
+                    time::OffsetDateTime::now_utc() + CONFIG.get().unwrap().ttl(tp.ttl_seconds());
+                trace!("writing to storage");
+                let q = STORAGE
+                    .get()
+                    .unwrap()
+                    .put(StoredIota {
+                        pattern: pat,
+                        data: ser_nbt,
+                        password: password.to_vec(),
+                        deletion,
+                        iota_type: san.iota_type().to_owned(),
+                        sanitized: san.consumed_entity,
+                    })
+                    .await;
+                match q {
+                    Ok(_resp) => {
+                        trace!("creating packet");
+                        let fbmoment = FlatbufferMoment::new(&password);
+                        let psargs = PutSuccessArgs {
+                            password: Some(&fbmoment),
+                            sanatized_entity: san.consumed_entity,
+                            stripped_items: Some(create_strings(fbb, &san.stripped_items)),
+                            report: Some(create_report(fbb, &san)),
+                            expiry: deletion.unix_timestamp(),
+                        };
+                        let pargs = PacketArgs {
+                            data_type: PacketData::PutSuccess,
+                            data: Some(PutSuccess::create(fbb, &psargs).as_union_value()),
+                        };
+                        responses.push(Packet::create(fbb, &pargs));
+                    }
+                    Err(StorageError::AlreadyExists(_)) => make_err_packet(
+                        fbb,
+                        responses,
+                        ErrorCode::PatternTaken,
+                        json!({ "pattern": pattern }),
+                    ),
+                    Err(ohno) => {
+                        error!("failed to write to storage: {}", ohno);
+                        make_err_packet(fbb, responses, ErrorCode::Internal, json!({}))
+                    }
+                }
             }
-            None => {
-                warn!("why send a message if you aren't gonna send any packets!")
+        },
+    }
+}
+
+async fn handle_try_replace<'a>(
+    tr: TryReplace<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let (Some(pattern), Some(password), Some(nbt)) = (tr.pattern(), tr.password(), tr.nbt()) else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    let Some((ser_nbt, san)) = prepare_nbt(nbt.bytes(), &mut 0, fbb, responses) else {
+        return;
+    };
+    let Some(pat) = pattern_key(pattern, tr.namespace(), fbb, responses) else {
+        return;
+    };
+    let deletion = if tr.keep_deletion() {
+        None
+    } else {
+        Some(time::OffsetDateTime::now_utc() + CONFIG.get().unwrap().ttl(0))
+    };
+    trace!("replacing in storage");
+    let q = STORAGE
+        .get()
+        .unwrap()
+        .replace(
+            &pat,
+            &password.0[..],
+            ser_nbt,
+            san.iota_type(),
+            san.consumed_entity,
+            deletion,
+        )
+        .await;
+    match q {
+        Ok(()) => {
+            trace!("creating packet");
+            let rsargs = ReplaceSuccessArgs {
+                sanatized_entity: san.consumed_entity,
+                stripped_items: Some(create_strings(fbb, &san.stripped_items)),
+                report: Some(create_report(fbb, &san)),
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::ReplaceSuccess,
+                data: Some(ReplaceSuccess::create(fbb, &rsargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(StorageError::NotFound(_)) => make_err_packet(
+            fbb,
+            responses,
+            ErrorCode::NotFound,
+            json!({ "pattern": pattern }),
+        ),
+        Err(StorageError::WrongPassword(_)) => make_err_packet(
+            fbb,
+            responses,
+            ErrorCode::WrongPassword,
+            json!({ "pattern": pattern }),
+        ),
+        Err(ohno) => {
+            error!("failed to replace in storage: {}", ohno);
+            make_err_packet(fbb, responses, ErrorCode::Internal, json!({}))
+        }
+    }
+}
+
+async fn handle_try_refresh<'a>(
+    tr: TryRefresh<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let (Some(pattern), Some(password)) = (tr.pattern(), tr.password()) else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    let Some(pat) = pattern_key(pattern, tr.namespace(), fbb, responses) else {
+        return;
+    };
+    let deletion = time::OffsetDateTime::now_utc() + CONFIG.get().unwrap().ttl(tr.ttl_seconds());
+    trace!("refreshing in storage");
+    let q = STORAGE
+        .get()
+        .unwrap()
+        .refresh(&pat, &password.0[..], deletion)
+        .await;
+    match q {
+        Ok(()) => {
+            trace!("creating packet");
+            let rsargs = RefreshSuccessArgs {
+                expiry: deletion.unix_timestamp(),
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::RefreshSuccess,
+                data: Some(RefreshSuccess::create(fbb, &rsargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(StorageError::NotFound(_)) => make_err_packet(
+            fbb,
+            responses,
+            ErrorCode::NotFound,
+            json!({ "pattern": pattern }),
+        ),
+        Err(StorageError::WrongPassword(_)) => make_err_packet(
+            fbb,
+            responses,
+            ErrorCode::WrongPassword,
+            json!({ "pattern": pattern }),
+        ),
+        Err(ohno) => {
+            error!("failed to refresh in storage: {}", ohno);
+            make_err_packet(fbb, responses, ErrorCode::Internal, json!({}))
+        }
+    }
+}
+
+/// the error to send back for a failed future operation on the slot at `index`
+fn future_err_packet<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+    err: FutureError,
+    index: u32,
+) {
+    let code = match err {
+        FutureError::Gone => ErrorCode::FutureGone,
+        FutureError::WrongPassword => ErrorCode::WrongPassword,
+        FutureError::NotDone => ErrorCode::FutureNotDone,
+        FutureError::AlreadyCompleted => ErrorCode::FutureCompleted,
+        FutureError::Full => {
+            warn!("ran out of future slots");
+            let max_futures = CONFIG.get().unwrap().max_futures;
+            return make_err_packet(
+                fbb,
+                responses,
+                ErrorCode::FuturesFull,
+                json!({ "max_futures": max_futures }),
+            );
+        }
+    };
+    make_err_packet(fbb, responses, code, json!({ "index": index }))
+}
+
+/// copies the NBT of each iota in `result` into the reply
+fn create_iotas<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    result: &[Vec<u8>],
+) -> WIPOffset<Vector<'a, ForwardsUOffset<Iota<'a>>>> {
+    let iotas = result
+        .iter()
+        .map(|nbt| {
+            let iargs = IotaArgs {
+                nbt: Some(fbb.create_vector(nbt)),
+            };
+            Iota::create(fbb, &iargs)
+        })
+        .collect::<Vec<_>>();
+    fbb.create_vector(&iotas)
+}
+
+fn handle_try_create_future<'a>(
+    tc: TryCreateFuture<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    trace!("generating password");
+    let mut password = [0u8; 255];
+    {
+        let mut rng = rand::thread_rng();
+        rng.fill(&mut password);
+    }
+    let deletion = time::OffsetDateTime::now_utc() + CONFIG.get().unwrap().ttl(tc.ttl_seconds());
+    let created = FUTURES
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .create(password.to_vec(), deletion);
+    match created {
+        Ok((index, uuid)) => {
+            trace!("creating packet");
+            let fhargs = FutureHandleArgs {
+                index,
+                uuid: Some(fbb.create_vector(&uuid)),
+            };
+            let handle = FutureHandle::create(fbb, &fhargs);
+            let fbmoment = FlatbufferMoment::new(&password);
+            let cfargs = CreateFutureSuccessArgs {
+                handle: Some(handle),
+                password: Some(&fbmoment),
+                expiry: deletion.unix_timestamp(),
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::CreateFutureSuccess,
+                data: Some(CreateFutureSuccess::create(fbb, &cfargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(err) => future_err_packet(fbb, responses, err, 0),
+    }
+}
+
+fn handle_try_complete_future<'a>(
+    tc: TryCompleteFuture<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let (Some(handle), Some(password), Some(result)) = (tc.handle(), tc.password(), tc.result())
+    else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    let Some(uuid) = handle.uuid() else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    let mut sanatized = vec![];
+    let mut consumed_entity = false;
+    //max_iotas is for the whole result, not each iota in it
+    let mut iotas = 0;
+    for iota in result {
+        let Some(nbt) = iota.nbt() else {
+            return why_is_a_field_empty(fbb, responses);
+        };
+        let Some((ser_nbt, san)) = prepare_nbt(nbt.bytes(), &mut iotas, fbb, responses) else {
+            return;
+        };
+        consumed_entity |= san.consumed_entity;
+        sanatized.push(ser_nbt);
+    }
+    let completed = FUTURES.get().unwrap().lock().unwrap().complete(
+        handle.index(),
+        uuid.bytes(),
+        &password.0[..],
+        sanatized,
+    );
+    match completed {
+        Ok(()) => {
+            trace!("creating packet");
+            let cfargs = CompleteFutureSuccessArgs {
+                sanatized_entity: consumed_entity,
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::CompleteFutureSuccess,
+                data: Some(CompleteFutureSuccess::create(fbb, &cfargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(err) => future_err_packet(fbb, responses, err, handle.index()),
+    }
+}
+
+fn handle_try_poll_future<'a>(
+    tp: TryPollFuture<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let Some((index, uuid)) = tp.handle().and_then(|h| Some((h.index(), h.uuid()?))) else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    let polled = FUTURES
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .poll(index, uuid.bytes());
+    match polled {
+        Ok(exited) => {
+            let pfargs = PollFutureSuccessArgs { exited };
+            let pargs = PacketArgs {
+                data_type: PacketData::PollFutureSuccess,
+                data: Some(PollFutureSuccess::create(fbb, &pfargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(err) => future_err_packet(fbb, responses, err, index),
+    }
+}
+
+fn handle_try_fetch_future<'a>(
+    tf: TryFetchFuture<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let Some((index, uuid)) = tf.handle().and_then(|h| Some((h.index(), h.uuid()?))) else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    let fetched = FUTURES
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .fetch(index, uuid.bytes())
+        .map(|result| result.to_vec());
+    match fetched {
+        Ok(result) => {
+            let ffargs = FetchFutureSuccessArgs {
+                result: Some(create_iotas(fbb, &result)),
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::FetchFutureSuccess,
+                data: Some(FetchFutureSuccess::create(fbb, &ffargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(err) => future_err_packet(fbb, responses, err, index),
+    }
+}
+
+/// the error sent back when the item lists turn an item away
+fn item_rejected<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+    rejection: ItemRejection,
+) {
+    info!("rejected item {} by {}", rejection.id, rejection.list);
+    make_err_packet(fbb, responses, ErrorCode::ItemRejected, rejection.to_json())
+}
+
+/// the `id` of the item stack NBT in `item`
+///
+/// `read_nbt` recurses, so callers have to hold the item to the iota limits with
+/// [`check_limits`] before asking for its id
+fn item_id(mut item: &[u8]) -> Result<String, String> {
+    let (stack, _) = read_nbt(&mut item, Flavor::Uncompressed).map_err(|e| e.to_string())?;
+    let id = stack.get::<_, &str>("id").map_err(|e| e.to_string())?;
+    Ok(id.to_owned())
+}
+
+async fn handle_try_push_item<'a>(
+    tp: TryPushItem<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let (Some(pattern), Some(item)) = (tp.pattern(), tp.item()) else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    if tp.count() == 0 {
+        return why_is_a_field_empty(fbb, responses);
+    }
+    trace!("checking item");
+    if let Err(e) = check_limits(item.bytes(), &CONFIG.get().unwrap().sanitize.limits) {
+        return refuse_iota(fbb, responses, e);
+    }
+    let id = match item_id(item.bytes()) {
+        Ok(id) => id,
+        Err(reason) => {
+            warn!("item nbt was invalid");
+            make_err_packet(
+                fbb,
+                responses,
+                ErrorCode::InvalidNbt,
+                json!({ "reason": reason }),
+            );
+            return;
+        }
+    };
+    let tags = tp
+        .tags()
+        .map(|tags| {
+            tags.iter()
+                .map(|tag| tag.trim_start_matches('#').to_owned())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if let Some(tag) = tags.iter().find(|tag| !is_resource_location(tag)) {
+        warn!("item tag {:?} is not a resource location", tag);
+        return make_err_packet(
+            fbb,
+            responses,
+            ErrorCode::BadRequest,
+            json!({ "reason": "item tags must be resource locations (namespace:path)", "tag": tag }),
+        );
+    }
+    if let Err(rejection) = CONFIG.get().unwrap().item_policy.check(&id, &tags) {
+        return item_rejected(fbb, responses, rejection);
+    }
+    let Some(pat) = pattern_key(pattern, tp.namespace(), fbb, responses) else {
+        return;
+    };
+    let deletion = time::OffsetDateTime::now_utc() + CONFIG.get().unwrap().ttl(tp.ttl_seconds());
+    trace!("writing to storage");
+    let q = STORAGE
+        .get()
+        .unwrap()
+        .push_item(StoredItem {
+            pattern: pat,
+            item: item.bytes().to_vec(),
+            count: tp.count(),
+            tags,
+            deletion,
+        })
+        .await;
+    match q {
+        Ok(()) => {
+            trace!("creating packet");
+            let psargs = PushItemSuccessArgs {
+                expiry: deletion.unix_timestamp(),
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::PushItemSuccess,
+                data: Some(PushItemSuccess::create(fbb, &psargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(StorageError::AlreadyExists(_)) => make_err_packet(
+            fbb,
+            responses,
+            ErrorCode::PatternTaken,
+            json!({ "pattern": pattern }),
+        ),
+        Err(ohno) => {
+            error!("failed to write item to storage: {}", ohno);
+            make_err_packet(fbb, responses, ErrorCode::Internal, json!({}))
+        }
+    }
+}
+
+async fn handle_try_pull_item<'a>(
+    tp: TryPullItem<'_>,
+    fbb: &mut FlatBufferBuilder<'a>,
+    responses: &mut Vec<WIPOffset<Packet<'a>>>,
+) {
+    let Some(pattern) = tp.pattern() else {
+        return why_is_a_field_empty(fbb, responses);
+    };
+    let Some(pat) = pattern_key(pattern, tp.namespace(), fbb, responses) else {
+        return;
+    };
+    trace!("pulling from storage");
+    let storage = STORAGE.get().unwrap();
+    //check the lists before taking the items, so rejected items stay put
+    let q = match storage.peek_item(&pat).await {
+        Ok(stored) => {
+            //items stored before the limits were checked on push, or under looser ones
+            if let Err(e) = check_limits(&stored.item, &CONFIG.get().unwrap().sanitize.limits) {
+                return refuse_iota(fbb, responses, e);
+            }
+            match item_id(&stored.item) {
+                Ok(id) => match CONFIG.get().unwrap().item_policy.check(&id, &stored.tags) {
+                    Ok(()) => storage.pull_item(&stored).await.map(|()| stored),
+                    Err(rejection) => return item_rejected(fbb, responses, rejection),
+                },
+                Err(reason) => Err(StorageError::Backend(format!(
+                    "stored item at {} is broken: {}",
+                    pat, reason
+                ))),
             }
         }
+        Err(err) => Err(err),
+    };
+    match q {
+        Ok(stored) => {
+            trace!("creating packet");
+            let psargs = PullItemSuccessArgs {
+                item: Some(fbb.create_vector(&stored.item)),
+                count: stored.count,
+            };
+            let pargs = PacketArgs {
+                data_type: PacketData::PullItemSuccess,
+                data: Some(PullItemSuccess::create(fbb, &psargs).as_union_value()),
+            };
+            responses.push(Packet::create(fbb, &pargs));
+        }
+        Err(StorageError::NotFound(_)) => make_err_packet(
+            fbb,
+            responses,
+            ErrorCode::NotFound,
+            json!({ "pattern": pattern }),
+        ),
+        Err(ohno) => {
+            error!("failed to pull item from storage: {}", ohno);
+            make_err_packet(fbb, responses, ErrorCode::Internal, json!({}))
+        }
     }
 }
@@ -0,0 +1,175 @@
+#![allow(dead_code)]
+//! Server side settings, read once from the environment (or `.env`) at startup.
+use std::{collections::HashMap, env, fmt::Display, path::Path, str::FromStr};
+use time::Duration;
+use tracing::{info, warn};
+
+use crate::{
+    frame::DEFAULT_MAX_FRAME_SIZE,
+    items::{IdList, ItemPolicy},
+    pattern::PatternPolicy,
+    sanitize::{Action, Limits, SanitizePolicy, UnknownTypes},
+};
+
+/// where the sanitization rules are read from when `SANITIZE_RULES` isn't set
+pub const DEFAULT_SANITIZE_RULES: &str = "sanitize_rules.json";
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// largest frame a client may send, in bytes
+    pub max_frame_size: u32,
+    /// how long an iota lives when the put doesn't ask for anything, in seconds
+    pub default_ttl: u64,
+    /// the longest an iota may live, in seconds
+    pub max_ttl: u64,
+    /// how many futures can exist at once
+    pub max_futures: usize,
+    /// most patterns a single TryList page can hold
+    pub max_list_page: u32,
+    /// how patterns and their namespaces are stored
+    pub patterns: PatternPolicy,
+    /// which items may be pushed or pulled
+    pub item_policy: ItemPolicy,
+    /// what happens to the iotas that get put
+    pub sanitize: SanitizePolicy,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            default_ttl: 60 * 60,
+            max_ttl: 24 * 60 * 60,
+            max_futures: 4096,
+            max_list_page: 256,
+            patterns: PatternPolicy::default(),
+            item_policy: ItemPolicy::default(),
+            sanitize: SanitizePolicy::default(),
+        }
+    }
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        let default = Config::default();
+        let max_ttl = env_or("MAX_TTL", default.max_ttl);
+        let mut default_ttl = env_or("DEFAULT_TTL", default.default_ttl);
+        if default_ttl > max_ttl {
+            warn!(
+                "DEFAULT_TTL ({}) is longer than MAX_TTL ({}), using MAX_TTL",
+                default_ttl, max_ttl
+            );
+            default_ttl = max_ttl;
+        }
+        Config {
+            max_frame_size: env_or("MAX_FRAME_SIZE", default.max_frame_size),
+            default_ttl,
+            max_ttl,
+            max_futures: env_or("MAX_FUTURES", default.max_futures),
+            max_list_page: env_or("MAX_LIST_PAGE", default.max_list_page),
+            patterns: PatternPolicy {
+                canonical: env_or("CANONICAL_PATTERNS", default.patterns.canonical),
+                global_namespaces: match env::var("GLOBAL_NAMESPACES") {
+                    Ok(_) => env_list("GLOBAL_NAMESPACES")
+                        .into_iter()
+                        .map(|ns| ns.to_ascii_lowercase())
+                        .collect(),
+                    Err(_) => default.patterns.global_namespaces,
+                },
+            },
+            item_policy: ItemPolicy {
+                items: IdList::new(
+                    env_list("BLACKLIST_IMPORT_ITEMS"),
+                    env_or("INVERT_IMPORT_ITEMS", false),
+                ),
+                tags: IdList::new(
+                    env_list("BLACKLIST_IMPORT_TAGS")
+                        .into_iter()
+                        .map(|tag| tag.trim_start_matches('#').to_owned()),
+                    env_or("INVERT_IMPORT_TAGS", false),
+                ),
+            },
+            sanitize: SanitizePolicy {
+                rules: sanitize_rules(default.sanitize.rules),
+                unknown_types: env_or::<UnknownTypes>(
+                    "UNKNOWN_IOTA_TYPES",
+                    default.sanitize.unknown_types,
+                ),
+                drop_nbt: IdList::new(
+                    env_list("BLACKLIST_DROP_NBT"),
+                    env_or("INVERT_DROP_NBT", false),
+                ),
+                limits: Limits {
+                    max_depth: env_or("MAX_IOTA_DEPTH", default.sanitize.limits.max_depth),
+                    max_bytes: env_or("MAX_IOTA_BYTES", default.sanitize.limits.max_bytes),
+                    max_iotas: env_or("MAX_IOTAS", default.sanitize.limits.max_iotas),
+                },
+            },
+        }
+    }
+
+    /// the time to live for a put asking for `requested` seconds, 0 meaning the default
+    pub fn ttl(&self, requested: u32) -> Duration {
+        let secs = match requested {
+            0 => self.default_ttl,
+            requested => u64::from(requested).min(self.max_ttl),
+        };
+        Duration::seconds(secs.try_into().unwrap_or(i64::MAX))
+    }
+}
+
+/// loads the rule file from `SANITIZE_RULES`, keeping the built in rules if there is no file
+fn sanitize_rules(default: HashMap<String, Vec<Action>>) -> HashMap<String, Vec<Action>> {
+    let path = env::var("SANITIZE_RULES").unwrap_or(DEFAULT_SANITIZE_RULES.to_owned());
+    let path = Path::new(&path);
+    if !path.exists() {
+        info!(
+            "no sanitization rules at {}, using the built in ones",
+            path.display()
+        );
+        return default;
+    }
+    //a broken rule file could let entities through, so refuse to start instead
+    let rules = SanitizePolicy::load_rules(path).unwrap_or_else(|e| {
+        panic!(
+            "failed to load sanitization rules {}: {}",
+            path.display(),
+            e
+        )
+    });
+    info!(
+        "loaded sanitization rules for {} iota types from {}",
+        rules.len(),
+        path.display()
+    );
+    rules
+}
+
+/// reads a comma separated list from `key`, empty if it is unset
+fn env_list(key: &str) -> Vec<String> {
+    env::var(key)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// reads `key` from the environment, falling back to `default` if it is unset or unparsable
+fn env_or<T>(key: &str, default: T) -> T
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match env::var(key) {
+        Ok(value) => value.trim().parse().unwrap_or_else(|e| {
+            warn!(
+                "{} is set to {:?} which is invalid ({}), ignoring it",
+                key, value, e
+            );
+            default
+        }),
+        Err(_) => default,
+    }
+}
@@ -0,0 +1,134 @@
+#![allow(dead_code)]
+//! The registry of `ErrorResponse` codes.
+//!
+//! `ErrorResponse.id` is one of the [`ErrorCode`]s below and `ErrorResponse.other`
+//! is always a JSON object of the form
+//! `{"error": "<name>", "message": "<human readable text>", ...details}`
+//! so the mod can branch on `id` (or `error`) and show `message` to the player.
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// the message or packet could not be understood (bad flatbuffer, unknown packet type, s2c packet)
+    BadRequest,
+    /// the password given does not match the one handed out by `PutSuccess`
+    WrongPassword,
+    /// nothing is stored at that pattern
+    NotFound,
+    /// something is already stored at that pattern
+    PatternTaken,
+    /// the pattern is not a valid angle signature
+    InvalidPattern,
+    /// the frame or iota is bigger than the server allows
+    TooLarge,
+    /// the iota is bigger, nests deeper or holds more iotas than the server allows
+    IotaTooLarge,
+    /// the iota holds a type the server does not accept
+    UnknownIotaType,
+    /// the NBT sent (iota or item) could not be read
+    InvalidNbt,
+    /// a required field of the packet was left empty
+    MissingField,
+    /// the future handle is invalid, the future expired or its slot was reused
+    FutureGone,
+    /// the future has already been completed
+    FutureCompleted,
+    /// the future has not been completed yet
+    FutureNotDone,
+    /// the client is sending requests too quickly
+    RateLimited,
+    /// the item is not allowed through by the server's item lists
+    ItemRejected,
+    /// something went wrong on the server, details are only logged
+    Internal,
+    /// the `Messages.version` is not one the server speaks
+    UnsupportedVersion,
+    /// there is no room for another future
+    FuturesFull,
+}
+
+impl ErrorCode {
+    /// the `ErrorResponse.id` sent for this error
+    pub fn id(self) -> u16 {
+        match self {
+            ErrorCode::BadRequest => 400,
+            ErrorCode::WrongPassword => 403,
+            ErrorCode::NotFound => 404,
+            ErrorCode::UnknownIotaType => 406,
+            ErrorCode::PatternTaken => 409,
+            ErrorCode::InvalidPattern => 412,
+            ErrorCode::TooLarge => 413,
+            ErrorCode::IotaTooLarge => 414,
+            ErrorCode::InvalidNbt => 415,
+            ErrorCode::MissingField => 422,
+            ErrorCode::FutureGone => 410,
+            ErrorCode::FutureCompleted => 423,
+            ErrorCode::FutureNotDone => 425,
+            ErrorCode::RateLimited => 429,
+            ErrorCode::ItemRejected => 451,
+            ErrorCode::Internal => 500,
+            ErrorCode::UnsupportedVersion => 505,
+            ErrorCode::FuturesFull => 507,
+        }
+    }
+
+    /// the `error` field of the JSON payload
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorCode::BadRequest => "bad_request",
+            ErrorCode::WrongPassword => "wrong_password",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::PatternTaken => "pattern_taken",
+            ErrorCode::InvalidPattern => "invalid_pattern",
+            ErrorCode::TooLarge => "too_large",
+            ErrorCode::IotaTooLarge => "iota_too_large",
+            ErrorCode::UnknownIotaType => "unknown_iota_type",
+            ErrorCode::InvalidNbt => "invalid_nbt",
+            ErrorCode::MissingField => "missing_field",
+            ErrorCode::FutureGone => "future_gone",
+            ErrorCode::FutureCompleted => "future_completed",
+            ErrorCode::FutureNotDone => "future_not_done",
+            ErrorCode::RateLimited => "rate_limited",
+            ErrorCode::ItemRejected => "item_rejected",
+            ErrorCode::Internal => "internal",
+            ErrorCode::UnsupportedVersion => "unsupported_version",
+            ErrorCode::FuturesFull => "futures_full",
+        }
+    }
+
+    /// the `message` field of the JSON payload
+    pub fn message(self) -> &'static str {
+        match self {
+            ErrorCode::BadRequest => "the server could not understand the request",
+            ErrorCode::WrongPassword => "the password does not match",
+            ErrorCode::NotFound => "nothing is stored at that pattern",
+            ErrorCode::PatternTaken => "something is already stored at that pattern",
+            ErrorCode::InvalidPattern => "that is not a pattern that can be drawn",
+            ErrorCode::TooLarge => "the request is too large",
+            ErrorCode::IotaTooLarge => "the iota is too big or too deeply nested",
+            ErrorCode::UnknownIotaType => "the server does not accept that type of iota",
+            ErrorCode::InvalidNbt => "the NBT sent is not valid",
+            ErrorCode::MissingField => "please make sure to fill all fields",
+            ErrorCode::FutureGone => "the future no longer exists",
+            ErrorCode::FutureCompleted => "the future has already exited",
+            ErrorCode::FutureNotDone => "the future has not exited yet",
+            ErrorCode::RateLimited => "too many requests, slow down",
+            ErrorCode::ItemRejected => "that item is not allowed through the overmind",
+            ErrorCode::Internal => "something went wrong on the server",
+            ErrorCode::UnsupportedVersion => "the server does not speak that protocol version",
+            ErrorCode::FuturesFull => "the server has no room for more futures",
+        }
+    }
+
+    /// builds the JSON for `ErrorResponse.other`, `details` (if an object) is merged in
+    pub fn to_json(self, details: Value) -> String {
+        let mut payload = json!({
+            "error": self.name(),
+            "message": self.message(),
+        });
+        if let (Value::Object(payload), Value::Object(details)) = (&mut payload, details) {
+            payload.extend(details);
+        }
+        payload.to_string()
+    }
+}
@@ -33,13 +33,13 @@ pub mod hex_flatbuffer {
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
-    pub const ENUM_MAX_PACKET_DATA: u8 = 7;
+    pub const ENUM_MAX_PACKET_DATA: u8 = 29;
     #[deprecated(
         since = "2.0.0",
         note = "Use associated constants instead. This will no longer be generated in 2021."
     )]
     #[allow(non_camel_case_types)]
-    pub const ENUM_VALUES_PACKET_DATA: [PacketData; 8] = [
+    pub const ENUM_VALUES_PACKET_DATA: [PacketData; 30] = [
         PacketData::NONE,
         PacketData::TryPut,
         PacketData::PutSuccess,
@@ -48,6 +48,28 @@ pub mod hex_flatbuffer {
         PacketData::TryDelete,
         PacketData::DeleteSuccess,
         PacketData::ErrorResponse,
+        PacketData::TryHandshake,
+        PacketData::HandshakeSuccess,
+        PacketData::TryReplace,
+        PacketData::ReplaceSuccess,
+        PacketData::TryRefresh,
+        PacketData::RefreshSuccess,
+        PacketData::TryCreateFuture,
+        PacketData::CreateFutureSuccess,
+        PacketData::TryCompleteFuture,
+        PacketData::CompleteFutureSuccess,
+        PacketData::TryPollFuture,
+        PacketData::PollFutureSuccess,
+        PacketData::TryFetchFuture,
+        PacketData::FetchFutureSuccess,
+        PacketData::TryPushItem,
+        PacketData::PushItemSuccess,
+        PacketData::TryPullItem,
+        PacketData::PullItemSuccess,
+        PacketData::TryList,
+        PacketData::ListSuccess,
+        PacketData::TryExists,
+        PacketData::ExistsSuccess,
     ];
 
     #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -63,9 +85,31 @@ pub mod hex_flatbuffer {
         pub const TryDelete: Self = Self(5);
         pub const DeleteSuccess: Self = Self(6);
         pub const ErrorResponse: Self = Self(7);
+        pub const TryHandshake: Self = Self(8);
+        pub const HandshakeSuccess: Self = Self(9);
+        pub const TryReplace: Self = Self(10);
+        pub const ReplaceSuccess: Self = Self(11);
+        pub const TryRefresh: Self = Self(12);
+        pub const RefreshSuccess: Self = Self(13);
+        pub const TryCreateFuture: Self = Self(14);
+        pub const CreateFutureSuccess: Self = Self(15);
+        pub const TryCompleteFuture: Self = Self(16);
+        pub const CompleteFutureSuccess: Self = Self(17);
+        pub const TryPollFuture: Self = Self(18);
+        pub const PollFutureSuccess: Self = Self(19);
+        pub const TryFetchFuture: Self = Self(20);
+        pub const FetchFutureSuccess: Self = Self(21);
+        pub const TryPushItem: Self = Self(22);
+        pub const PushItemSuccess: Self = Self(23);
+        pub const TryPullItem: Self = Self(24);
+        pub const PullItemSuccess: Self = Self(25);
+        pub const TryList: Self = Self(26);
+        pub const ListSuccess: Self = Self(27);
+        pub const TryExists: Self = Self(28);
+        pub const ExistsSuccess: Self = Self(29);
 
         pub const ENUM_MIN: u8 = 0;
-        pub const ENUM_MAX: u8 = 7;
+        pub const ENUM_MAX: u8 = 29;
         pub const ENUM_VALUES: &'static [Self] = &[
             Self::NONE,
             Self::TryPut,
@@ -75,6 +119,28 @@ pub mod hex_flatbuffer {
             Self::TryDelete,
             Self::DeleteSuccess,
             Self::ErrorResponse,
+            Self::TryHandshake,
+            Self::HandshakeSuccess,
+            Self::TryReplace,
+            Self::ReplaceSuccess,
+            Self::TryRefresh,
+            Self::RefreshSuccess,
+            Self::TryCreateFuture,
+            Self::CreateFutureSuccess,
+            Self::TryCompleteFuture,
+            Self::CompleteFutureSuccess,
+            Self::TryPollFuture,
+            Self::PollFutureSuccess,
+            Self::TryFetchFuture,
+            Self::FetchFutureSuccess,
+            Self::TryPushItem,
+            Self::PushItemSuccess,
+            Self::TryPullItem,
+            Self::PullItemSuccess,
+            Self::TryList,
+            Self::ListSuccess,
+            Self::TryExists,
+            Self::ExistsSuccess,
         ];
         /// Returns the variant's name or "" if unknown.
         pub fn variant_name(self) -> Option<&'static str> {
@@ -87,6 +153,28 @@ pub mod hex_flatbuffer {
                 Self::TryDelete => Some("TryDelete"),
                 Self::DeleteSuccess => Some("DeleteSuccess"),
                 Self::ErrorResponse => Some("ErrorResponse"),
+                Self::TryHandshake => Some("TryHandshake"),
+                Self::HandshakeSuccess => Some("HandshakeSuccess"),
+                Self::TryReplace => Some("TryReplace"),
+                Self::ReplaceSuccess => Some("ReplaceSuccess"),
+                Self::TryRefresh => Some("TryRefresh"),
+                Self::RefreshSuccess => Some("RefreshSuccess"),
+                Self::TryCreateFuture => Some("TryCreateFuture"),
+                Self::CreateFutureSuccess => Some("CreateFutureSuccess"),
+                Self::TryCompleteFuture => Some("TryCompleteFuture"),
+                Self::CompleteFutureSuccess => Some("CompleteFutureSuccess"),
+                Self::TryPollFuture => Some("TryPollFuture"),
+                Self::PollFutureSuccess => Some("PollFutureSuccess"),
+                Self::TryFetchFuture => Some("TryFetchFuture"),
+                Self::FetchFutureSuccess => Some("FetchFutureSuccess"),
+                Self::TryPushItem => Some("TryPushItem"),
+                Self::PushItemSuccess => Some("PushItemSuccess"),
+                Self::TryPullItem => Some("TryPullItem"),
+                Self::PullItemSuccess => Some("PullItemSuccess"),
+                Self::TryList => Some("TryList"),
+                Self::ListSuccess => Some("ListSuccess"),
+                Self::TryExists => Some("TryExists"),
+                Self::ExistsSuccess => Some("ExistsSuccess"),
                 _ => None,
             }
         }
@@ -223,15 +311,15 @@ pub mod hex_flatbuffer {
         }
     }
 
-    pub enum TryPutOffset {}
+    pub enum ReplacedTypeOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct TryPut<'a> {
+    pub struct ReplacedType<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for TryPut<'a> {
-        type Inner = TryPut<'a>;
+    impl<'a> flatbuffers::Follow<'a> for ReplacedType<'a> {
+        type Inner = ReplacedType<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -240,55 +328,51 @@ pub mod hex_flatbuffer {
         }
     }
 
-    impl<'a> TryPut<'a> {
-        pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
-        pub const VT_NBT: flatbuffers::VOffsetT = 6;
+    impl<'a> ReplacedType<'a> {
+        pub const VT_IOTA_TYPE: flatbuffers::VOffsetT = 4;
+        pub const VT_COUNT: flatbuffers::VOffsetT = 6;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            TryPut { _tab: table }
+            ReplacedType { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args TryPutArgs<'args>,
-        ) -> flatbuffers::WIPOffset<TryPut<'bldr>> {
-            let mut builder = TryPutBuilder::new(_fbb);
-            if let Some(x) = args.nbt {
-                builder.add_nbt(x);
-            }
-            if let Some(x) = args.pattern {
-                builder.add_pattern(x);
+            args: &'args ReplacedTypeArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ReplacedType<'bldr>> {
+            let mut builder = ReplacedTypeBuilder::new(_fbb);
+            builder.add_count(args.count);
+            if let Some(x) = args.iota_type {
+                builder.add_iota_type(x);
             }
             builder.finish()
         }
 
         #[inline]
-        pub fn pattern(&self) -> Option<&'a str> {
+        pub fn iota_type(&self) -> Option<&'a str> {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryPut::VT_PATTERN, None)
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(ReplacedType::VT_IOTA_TYPE, None)
             }
         }
         #[inline]
-        pub fn nbt(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+        pub fn count(&self) -> u32 {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
-                        TryPut::VT_NBT,
-                        None,
-                    )
+                    .get::<u32>(ReplacedType::VT_COUNT, Some(0))
+                    .unwrap()
             }
         }
     }
 
-    impl flatbuffers::Verifiable for TryPut<'_> {
+    impl flatbuffers::Verifiable for ReplacedType<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -297,80 +381,79 @@ pub mod hex_flatbuffer {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
                 .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
-                    "pattern",
-                    Self::VT_PATTERN,
-                    false,
-                )?
-                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
-                    "nbt",
-                    Self::VT_NBT,
+                    "iota_type",
+                    Self::VT_IOTA_TYPE,
                     false,
                 )?
+                .visit_field::<u32>("count", Self::VT_COUNT, false)?
                 .finish();
             Ok(())
         }
     }
-    pub struct TryPutArgs<'a> {
-        pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
-        pub nbt: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub struct ReplacedTypeArgs<'a> {
+        pub iota_type: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub count: u32,
     }
-    impl<'a> Default for TryPutArgs<'a> {
+    impl<'a> Default for ReplacedTypeArgs<'a> {
         #[inline]
         fn default() -> Self {
-            TryPutArgs {
-                pattern: None,
-                nbt: None,
+            ReplacedTypeArgs {
+                iota_type: None,
+                count: 0,
             }
         }
     }
 
-    pub struct TryPutBuilder<'a: 'b, 'b> {
+    pub struct ReplacedTypeBuilder<'a: 'b, 'b> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> TryPutBuilder<'a, 'b> {
+    impl<'a: 'b, 'b> ReplacedTypeBuilder<'a, 'b> {
         #[inline]
-        pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
-            self.fbb_
-                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPut::VT_PATTERN, pattern);
+        pub fn add_iota_type(&mut self, iota_type: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                ReplacedType::VT_IOTA_TYPE,
+                iota_type,
+            );
         }
         #[inline]
-        pub fn add_nbt(&mut self, nbt: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
-            self.fbb_
-                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPut::VT_NBT, nbt);
+        pub fn add_count(&mut self, count: u32) {
+            self.fbb_.push_slot::<u32>(ReplacedType::VT_COUNT, count, 0);
         }
         #[inline]
-        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryPutBuilder<'a, 'b> {
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> ReplacedTypeBuilder<'a, 'b> {
             let start = _fbb.start_table();
-            TryPutBuilder {
+            ReplacedTypeBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<TryPut<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<ReplacedType<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    impl core::fmt::Debug for TryPut<'_> {
+    impl core::fmt::Debug for ReplacedType<'_> {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let mut ds = f.debug_struct("TryPut");
-            ds.field("pattern", &self.pattern());
-            ds.field("nbt", &self.nbt());
+            let mut ds = f.debug_struct("ReplacedType");
+            ds.field("iota_type", &self.iota_type());
+            ds.field("count", &self.count());
             ds.finish()
         }
     }
-    pub enum PutSuccessOffset {}
+    pub enum SanitizeReportOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct PutSuccess<'a> {
+    pub struct SanitizeReport<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for PutSuccess<'a> {
-        type Inner = PutSuccess<'a>;
+    impl<'a> flatbuffers::Follow<'a> for SanitizeReport<'a> {
+        type Inner = SanitizeReport<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -379,51 +462,89 @@ pub mod hex_flatbuffer {
         }
     }
 
-    impl<'a> PutSuccess<'a> {
-        pub const VT_PASSWORD: flatbuffers::VOffsetT = 4;
-        pub const VT_SANATIZED_ENTITY: flatbuffers::VOffsetT = 6;
+    impl<'a> SanitizeReport<'a> {
+        pub const VT_REPLACED: flatbuffers::VOffsetT = 4;
+        pub const VT_REPLACED_PATHS: flatbuffers::VOffsetT = 6;
+        pub const VT_PASSED_UNKNOWN: flatbuffers::VOffsetT = 8;
+        pub const VT_UNKNOWN_TYPES: flatbuffers::VOffsetT = 10;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            PutSuccess { _tab: table }
+            SanitizeReport { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args PutSuccessArgs<'args>,
-        ) -> flatbuffers::WIPOffset<PutSuccess<'bldr>> {
-            let mut builder = PutSuccessBuilder::new(_fbb);
-            if let Some(x) = args.password {
-                builder.add_password(x);
+            args: &'args SanitizeReportArgs<'args>,
+        ) -> flatbuffers::WIPOffset<SanitizeReport<'bldr>> {
+            let mut builder = SanitizeReportBuilder::new(_fbb);
+            if let Some(x) = args.unknown_types {
+                builder.add_unknown_types(x);
             }
-            builder.add_sanatized_entity(args.sanatized_entity);
+            if let Some(x) = args.replaced_paths {
+                builder.add_replaced_paths(x);
+            }
+            if let Some(x) = args.replaced {
+                builder.add_replaced(x);
+            }
+            builder.add_passed_unknown(args.passed_unknown);
             builder.finish()
         }
 
         #[inline]
-        pub fn password(&self) -> Option<&'a FlatbufferMoment> {
+        pub fn replaced(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ReplacedType<'a>>>>
+        {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
-                self._tab
-                    .get::<FlatbufferMoment>(PutSuccess::VT_PASSWORD, None)
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ReplacedType>>,
+                >>(SanitizeReport::VT_REPLACED, None)
             }
         }
         #[inline]
-        pub fn sanatized_entity(&self) -> bool {
+        pub fn replaced_paths(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(SanitizeReport::VT_REPLACED_PATHS, None)
+            }
+        }
+        #[inline]
+        pub fn passed_unknown(&self) -> bool {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<bool>(PutSuccess::VT_SANATIZED_ENTITY, Some(false))
+                    .get::<bool>(SanitizeReport::VT_PASSED_UNKNOWN, Some(false))
                     .unwrap()
             }
         }
+        #[inline]
+        pub fn unknown_types(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(SanitizeReport::VT_UNKNOWN_TYPES, None)
+            }
+        }
     }
 
-    impl flatbuffers::Verifiable for PutSuccess<'_> {
+    impl flatbuffers::Verifiable for SanitizeReport<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -431,73 +552,128 @@ pub mod hex_flatbuffer {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-                .visit_field::<FlatbufferMoment>("password", Self::VT_PASSWORD, false)?
-                .visit_field::<bool>("sanatized_entity", Self::VT_SANATIZED_ENTITY, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ReplacedType>>,
+                >>("replaced", Self::VT_REPLACED, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("replaced_paths", Self::VT_REPLACED_PATHS, false)?
+                .visit_field::<bool>("passed_unknown", Self::VT_PASSED_UNKNOWN, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("unknown_types", Self::VT_UNKNOWN_TYPES, false)?
                 .finish();
             Ok(())
         }
     }
-    pub struct PutSuccessArgs<'a> {
-        pub password: Option<&'a FlatbufferMoment>,
-        pub sanatized_entity: bool,
+    pub struct SanitizeReportArgs<'a> {
+        pub replaced: Option<
+            flatbuffers::WIPOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ReplacedType<'a>>>,
+            >,
+        >,
+        pub replaced_paths: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub passed_unknown: bool,
+        pub unknown_types: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
     }
-    impl<'a> Default for PutSuccessArgs<'a> {
+    impl<'a> Default for SanitizeReportArgs<'a> {
         #[inline]
         fn default() -> Self {
-            PutSuccessArgs {
-                password: None,
-                sanatized_entity: false,
+            SanitizeReportArgs {
+                replaced: None,
+                replaced_paths: None,
+                passed_unknown: false,
+                unknown_types: None,
             }
         }
     }
 
-    pub struct PutSuccessBuilder<'a: 'b, 'b> {
+    pub struct SanitizeReportBuilder<'a: 'b, 'b> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> PutSuccessBuilder<'a, 'b> {
+    impl<'a: 'b, 'b> SanitizeReportBuilder<'a, 'b> {
         #[inline]
-        pub fn add_password(&mut self, password: &FlatbufferMoment) {
-            self.fbb_
-                .push_slot_always::<&FlatbufferMoment>(PutSuccess::VT_PASSWORD, password);
+        pub fn add_replaced(
+            &mut self,
+            replaced: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<ReplacedType<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                SanitizeReport::VT_REPLACED,
+                replaced,
+            );
         }
         #[inline]
-        pub fn add_sanatized_entity(&mut self, sanatized_entity: bool) {
+        pub fn add_replaced_paths(
+            &mut self,
+            replaced_paths: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                SanitizeReport::VT_REPLACED_PATHS,
+                replaced_paths,
+            );
+        }
+        #[inline]
+        pub fn add_passed_unknown(&mut self, passed_unknown: bool) {
             self.fbb_
-                .push_slot::<bool>(PutSuccess::VT_SANATIZED_ENTITY, sanatized_entity, false);
+                .push_slot::<bool>(SanitizeReport::VT_PASSED_UNKNOWN, passed_unknown, false);
         }
         #[inline]
-        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PutSuccessBuilder<'a, 'b> {
+        pub fn add_unknown_types(
+            &mut self,
+            unknown_types: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                SanitizeReport::VT_UNKNOWN_TYPES,
+                unknown_types,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> SanitizeReportBuilder<'a, 'b> {
             let start = _fbb.start_table();
-            PutSuccessBuilder {
+            SanitizeReportBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<PutSuccess<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<SanitizeReport<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    impl core::fmt::Debug for PutSuccess<'_> {
+    impl core::fmt::Debug for SanitizeReport<'_> {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let mut ds = f.debug_struct("PutSuccess");
-            ds.field("password", &self.password());
-            ds.field("sanatized_entity", &self.sanatized_entity());
+            let mut ds = f.debug_struct("SanitizeReport");
+            ds.field("replaced", &self.replaced());
+            ds.field("replaced_paths", &self.replaced_paths());
+            ds.field("passed_unknown", &self.passed_unknown());
+            ds.field("unknown_types", &self.unknown_types());
             ds.finish()
         }
     }
-    pub enum TryGetOffset {}
+    pub enum TryPutOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct TryGet<'a> {
+    pub struct TryPut<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for TryGet<'a> {
-        type Inner = TryGet<'a>;
+    impl<'a> flatbuffers::Follow<'a> for TryPut<'a> {
+        type Inner = TryPut<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -506,19 +682,29 @@ pub mod hex_flatbuffer {
         }
     }
 
-    impl<'a> TryGet<'a> {
+    impl<'a> TryPut<'a> {
         pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
+        pub const VT_NBT: flatbuffers::VOffsetT = 6;
+        pub const VT_TTL_SECONDS: flatbuffers::VOffsetT = 8;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 10;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            TryGet { _tab: table }
+            TryPut { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args TryGetArgs<'args>,
-        ) -> flatbuffers::WIPOffset<TryGet<'bldr>> {
-            let mut builder = TryGetBuilder::new(_fbb);
+            args: &'args TryPutArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryPut<'bldr>> {
+            let mut builder = TryPutBuilder::new(_fbb);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
+            }
+            builder.add_ttl_seconds(args.ttl_seconds);
+            if let Some(x) = args.nbt {
+                builder.add_nbt(x);
+            }
             if let Some(x) = args.pattern {
                 builder.add_pattern(x);
             }
@@ -532,12 +718,46 @@ pub mod hex_flatbuffer {
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryGet::VT_PATTERN, None)
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryPut::VT_PATTERN, None)
+            }
+        }
+        #[inline]
+        pub fn nbt(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        TryPut::VT_NBT,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn ttl_seconds(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TryPut::VT_TTL_SECONDS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn namespace(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryPut::VT_NAMESPACE, None)
             }
         }
     }
 
-    impl flatbuffers::Verifiable for TryGet<'_> {
+    impl flatbuffers::Verifiable for TryPut<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -550,61 +770,98 @@ pub mod hex_flatbuffer {
                     Self::VT_PATTERN,
                     false,
                 )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "nbt",
+                    Self::VT_NBT,
+                    false,
+                )?
+                .visit_field::<u32>("ttl_seconds", Self::VT_TTL_SECONDS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
                 .finish();
             Ok(())
         }
     }
-    pub struct TryGetArgs<'a> {
+    pub struct TryPutArgs<'a> {
         pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub nbt: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub ttl_seconds: u32,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
     }
-    impl<'a> Default for TryGetArgs<'a> {
+    impl<'a> Default for TryPutArgs<'a> {
         #[inline]
         fn default() -> Self {
-            TryGetArgs { pattern: None }
+            TryPutArgs {
+                pattern: None,
+                nbt: None,
+                ttl_seconds: 0,
+                namespace: None,
+            }
         }
     }
 
-    pub struct TryGetBuilder<'a: 'b, 'b> {
+    pub struct TryPutBuilder<'a: 'b, 'b> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> TryGetBuilder<'a, 'b> {
+    impl<'a: 'b, 'b> TryPutBuilder<'a, 'b> {
         #[inline]
         pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
             self.fbb_
-                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryGet::VT_PATTERN, pattern);
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPut::VT_PATTERN, pattern);
         }
         #[inline]
-        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryGetBuilder<'a, 'b> {
+        pub fn add_nbt(&mut self, nbt: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPut::VT_NBT, nbt);
+        }
+        #[inline]
+        pub fn add_ttl_seconds(&mut self, ttl_seconds: u32) {
+            self.fbb_
+                .push_slot::<u32>(TryPut::VT_TTL_SECONDS, ttl_seconds, 0);
+        }
+        #[inline]
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPut::VT_NAMESPACE, namespace);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryPutBuilder<'a, 'b> {
             let start = _fbb.start_table();
-            TryGetBuilder {
+            TryPutBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<TryGet<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryPut<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    impl core::fmt::Debug for TryGet<'_> {
+    impl core::fmt::Debug for TryPut<'_> {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let mut ds = f.debug_struct("TryGet");
+            let mut ds = f.debug_struct("TryPut");
             ds.field("pattern", &self.pattern());
+            ds.field("nbt", &self.nbt());
+            ds.field("ttl_seconds", &self.ttl_seconds());
+            ds.field("namespace", &self.namespace());
             ds.finish()
         }
     }
-    pub enum GetSuccessOffset {}
+    pub enum PutSuccessOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct GetSuccess<'a> {
+    pub struct PutSuccess<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for GetSuccess<'a> {
-        type Inner = GetSuccess<'a>;
+    impl<'a> flatbuffers::Follow<'a> for PutSuccess<'a> {
+        type Inner = PutSuccess<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -613,41 +870,98 @@ pub mod hex_flatbuffer {
         }
     }
 
-    impl<'a> GetSuccess<'a> {
-        pub const VT_NBT: flatbuffers::VOffsetT = 4;
+    impl<'a> PutSuccess<'a> {
+        pub const VT_PASSWORD: flatbuffers::VOffsetT = 4;
+        pub const VT_SANATIZED_ENTITY: flatbuffers::VOffsetT = 6;
+        pub const VT_EXPIRY: flatbuffers::VOffsetT = 8;
+        pub const VT_STRIPPED_ITEMS: flatbuffers::VOffsetT = 10;
+        pub const VT_REPORT: flatbuffers::VOffsetT = 12;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            GetSuccess { _tab: table }
+            PutSuccess { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args GetSuccessArgs<'args>,
-        ) -> flatbuffers::WIPOffset<GetSuccess<'bldr>> {
-            let mut builder = GetSuccessBuilder::new(_fbb);
-            if let Some(x) = args.nbt {
-                builder.add_nbt(x);
+            args: &'args PutSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<PutSuccess<'bldr>> {
+            let mut builder = PutSuccessBuilder::new(_fbb);
+            builder.add_expiry(args.expiry);
+            if let Some(x) = args.report {
+                builder.add_report(x);
+            }
+            if let Some(x) = args.stripped_items {
+                builder.add_stripped_items(x);
+            }
+            if let Some(x) = args.password {
+                builder.add_password(x);
             }
+            builder.add_sanatized_entity(args.sanatized_entity);
             builder.finish()
         }
 
         #[inline]
-        pub fn nbt(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+        pub fn password(&self) -> Option<&'a FlatbufferMoment> {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
-                        GetSuccess::VT_NBT,
+                    .get::<FlatbufferMoment>(PutSuccess::VT_PASSWORD, None)
+            }
+        }
+        #[inline]
+        pub fn sanatized_entity(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(PutSuccess::VT_SANATIZED_ENTITY, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn expiry(&self) -> i64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i64>(PutSuccess::VT_EXPIRY, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn stripped_items(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(PutSuccess::VT_STRIPPED_ITEMS, None)
+            }
+        }
+        #[inline]
+        pub fn report(&self) -> Option<SanitizeReport<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<SanitizeReport>>(
+                        PutSuccess::VT_REPORT,
                         None,
                     )
             }
         }
     }
 
-    impl flatbuffers::Verifiable for GetSuccess<'_> {
+    impl flatbuffers::Verifiable for PutSuccess<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -655,66 +969,117 @@ pub mod hex_flatbuffer {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
-                    "nbt",
-                    Self::VT_NBT,
+                .visit_field::<FlatbufferMoment>("password", Self::VT_PASSWORD, false)?
+                .visit_field::<bool>("sanatized_entity", Self::VT_SANATIZED_ENTITY, false)?
+                .visit_field::<i64>("expiry", Self::VT_EXPIRY, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("stripped_items", Self::VT_STRIPPED_ITEMS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<SanitizeReport>>(
+                    "report",
+                    Self::VT_REPORT,
                     false,
                 )?
                 .finish();
             Ok(())
         }
     }
-    pub struct GetSuccessArgs<'a> {
-        pub nbt: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub struct PutSuccessArgs<'a> {
+        pub password: Option<&'a FlatbufferMoment>,
+        pub sanatized_entity: bool,
+        pub expiry: i64,
+        pub stripped_items: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub report: Option<flatbuffers::WIPOffset<SanitizeReport<'a>>>,
     }
-    impl<'a> Default for GetSuccessArgs<'a> {
+    impl<'a> Default for PutSuccessArgs<'a> {
         #[inline]
         fn default() -> Self {
-            GetSuccessArgs { nbt: None }
+            PutSuccessArgs {
+                password: None,
+                sanatized_entity: false,
+                expiry: 0,
+                stripped_items: None,
+                report: None,
+            }
         }
     }
 
-    pub struct GetSuccessBuilder<'a: 'b, 'b> {
+    pub struct PutSuccessBuilder<'a: 'b, 'b> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> GetSuccessBuilder<'a, 'b> {
+    impl<'a: 'b, 'b> PutSuccessBuilder<'a, 'b> {
         #[inline]
-        pub fn add_nbt(&mut self, nbt: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+        pub fn add_password(&mut self, password: &FlatbufferMoment) {
             self.fbb_
-                .push_slot_always::<flatbuffers::WIPOffset<_>>(GetSuccess::VT_NBT, nbt);
+                .push_slot_always::<&FlatbufferMoment>(PutSuccess::VT_PASSWORD, password);
         }
         #[inline]
-        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GetSuccessBuilder<'a, 'b> {
+        pub fn add_sanatized_entity(&mut self, sanatized_entity: bool) {
+            self.fbb_
+                .push_slot::<bool>(PutSuccess::VT_SANATIZED_ENTITY, sanatized_entity, false);
+        }
+        #[inline]
+        pub fn add_expiry(&mut self, expiry: i64) {
+            self.fbb_.push_slot::<i64>(PutSuccess::VT_EXPIRY, expiry, 0);
+        }
+        #[inline]
+        pub fn add_stripped_items(
+            &mut self,
+            stripped_items: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                PutSuccess::VT_STRIPPED_ITEMS,
+                stripped_items,
+            );
+        }
+        #[inline]
+        pub fn add_report(&mut self, report: flatbuffers::WIPOffset<SanitizeReport<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<SanitizeReport>>(
+                    PutSuccess::VT_REPORT,
+                    report,
+                );
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PutSuccessBuilder<'a, 'b> {
             let start = _fbb.start_table();
-            GetSuccessBuilder {
+            PutSuccessBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<GetSuccess<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<PutSuccess<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    impl core::fmt::Debug for GetSuccess<'_> {
+    impl core::fmt::Debug for PutSuccess<'_> {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let mut ds = f.debug_struct("GetSuccess");
-            ds.field("nbt", &self.nbt());
+            let mut ds = f.debug_struct("PutSuccess");
+            ds.field("password", &self.password());
+            ds.field("sanatized_entity", &self.sanatized_entity());
+            ds.field("expiry", &self.expiry());
+            ds.field("stripped_items", &self.stripped_items());
+            ds.field("report", &self.report());
             ds.finish()
         }
     }
-    pub enum TryDeleteOffset {}
+    pub enum TryGetOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct TryDelete<'a> {
+    pub struct TryGet<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for TryDelete<'a> {
-        type Inner = TryDelete<'a>;
+    impl<'a> flatbuffers::Follow<'a> for TryGet<'a> {
+        type Inner = TryGet<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -723,22 +1088,22 @@ pub mod hex_flatbuffer {
         }
     }
 
-    impl<'a> TryDelete<'a> {
+    impl<'a> TryGet<'a> {
         pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
-        pub const VT_PASSWORD: flatbuffers::VOffsetT = 6;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 6;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            TryDelete { _tab: table }
+            TryGet { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args TryDeleteArgs<'args>,
-        ) -> flatbuffers::WIPOffset<TryDelete<'bldr>> {
-            let mut builder = TryDeleteBuilder::new(_fbb);
-            if let Some(x) = args.password {
-                builder.add_password(x);
+            args: &'args TryGetArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryGet<'bldr>> {
+            let mut builder = TryGetBuilder::new(_fbb);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
             }
             if let Some(x) = args.pattern {
                 builder.add_pattern(x);
@@ -753,22 +1118,22 @@ pub mod hex_flatbuffer {
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryDelete::VT_PATTERN, None)
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryGet::VT_PATTERN, None)
             }
         }
         #[inline]
-        pub fn password(&self) -> Option<&'a FlatbufferMoment> {
+        pub fn namespace(&self) -> Option<&'a str> {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<FlatbufferMoment>(TryDelete::VT_PASSWORD, None)
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryGet::VT_NAMESPACE, None)
             }
         }
     }
 
-    impl flatbuffers::Verifiable for TryDelete<'_> {
+    impl flatbuffers::Verifiable for TryGet<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -781,72 +1146,76 @@ pub mod hex_flatbuffer {
                     Self::VT_PATTERN,
                     false,
                 )?
-                .visit_field::<FlatbufferMoment>("password", Self::VT_PASSWORD, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
                 .finish();
             Ok(())
         }
     }
-    pub struct TryDeleteArgs<'a> {
+    pub struct TryGetArgs<'a> {
         pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
-        pub password: Option<&'a FlatbufferMoment>,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
     }
-    impl<'a> Default for TryDeleteArgs<'a> {
+    impl<'a> Default for TryGetArgs<'a> {
         #[inline]
         fn default() -> Self {
-            TryDeleteArgs {
+            TryGetArgs {
                 pattern: None,
-                password: None,
+                namespace: None,
             }
         }
     }
 
-    pub struct TryDeleteBuilder<'a: 'b, 'b> {
+    pub struct TryGetBuilder<'a: 'b, 'b> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> TryDeleteBuilder<'a, 'b> {
+    impl<'a: 'b, 'b> TryGetBuilder<'a, 'b> {
         #[inline]
         pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
             self.fbb_
-                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryDelete::VT_PATTERN, pattern);
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryGet::VT_PATTERN, pattern);
         }
         #[inline]
-        pub fn add_password(&mut self, password: &FlatbufferMoment) {
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
             self.fbb_
-                .push_slot_always::<&FlatbufferMoment>(TryDelete::VT_PASSWORD, password);
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryGet::VT_NAMESPACE, namespace);
         }
         #[inline]
-        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryDeleteBuilder<'a, 'b> {
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryGetBuilder<'a, 'b> {
             let start = _fbb.start_table();
-            TryDeleteBuilder {
+            TryGetBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<TryDelete<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryGet<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    impl core::fmt::Debug for TryDelete<'_> {
+    impl core::fmt::Debug for TryGet<'_> {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let mut ds = f.debug_struct("TryDelete");
+            let mut ds = f.debug_struct("TryGet");
             ds.field("pattern", &self.pattern());
-            ds.field("password", &self.password());
+            ds.field("namespace", &self.namespace());
             ds.finish()
         }
     }
-    pub enum DeleteSuccessOffset {}
+    pub enum GetSuccessOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct DeleteSuccess<'a> {
+    pub struct GetSuccess<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for DeleteSuccess<'a> {
-        type Inner = DeleteSuccess<'a>;
+    impl<'a> flatbuffers::Follow<'a> for GetSuccess<'a> {
+        type Inner = GetSuccess<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -855,77 +1224,108 @@ pub mod hex_flatbuffer {
         }
     }
 
-    impl<'a> DeleteSuccess<'a> {
+    impl<'a> GetSuccess<'a> {
+        pub const VT_NBT: flatbuffers::VOffsetT = 4;
+
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            DeleteSuccess { _tab: table }
+            GetSuccess { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            _args: &'args DeleteSuccessArgs,
-        ) -> flatbuffers::WIPOffset<DeleteSuccess<'bldr>> {
-            let mut builder = DeleteSuccessBuilder::new(_fbb);
+            args: &'args GetSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<GetSuccess<'bldr>> {
+            let mut builder = GetSuccessBuilder::new(_fbb);
+            if let Some(x) = args.nbt {
+                builder.add_nbt(x);
+            }
             builder.finish()
         }
+
+        #[inline]
+        pub fn nbt(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        GetSuccess::VT_NBT,
+                        None,
+                    )
+            }
+        }
     }
 
-    impl flatbuffers::Verifiable for DeleteSuccess<'_> {
+    impl flatbuffers::Verifiable for GetSuccess<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
             pos: usize,
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
-            v.visit_table(pos)?.finish();
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "nbt",
+                    Self::VT_NBT,
+                    false,
+                )?
+                .finish();
             Ok(())
         }
     }
-    pub struct DeleteSuccessArgs {}
-    impl<'a> Default for DeleteSuccessArgs {
+    pub struct GetSuccessArgs<'a> {
+        pub nbt: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for GetSuccessArgs<'a> {
         #[inline]
         fn default() -> Self {
-            DeleteSuccessArgs {}
+            GetSuccessArgs { nbt: None }
         }
     }
 
-    pub struct DeleteSuccessBuilder<'a: 'b, 'b> {
+    pub struct GetSuccessBuilder<'a: 'b, 'b> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> DeleteSuccessBuilder<'a, 'b> {
+    impl<'a: 'b, 'b> GetSuccessBuilder<'a, 'b> {
         #[inline]
-        pub fn new(
-            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-        ) -> DeleteSuccessBuilder<'a, 'b> {
+        pub fn add_nbt(&mut self, nbt: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(GetSuccess::VT_NBT, nbt);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GetSuccessBuilder<'a, 'b> {
             let start = _fbb.start_table();
-            DeleteSuccessBuilder {
+            GetSuccessBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<DeleteSuccess<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<GetSuccess<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    impl core::fmt::Debug for DeleteSuccess<'_> {
+    impl core::fmt::Debug for GetSuccess<'_> {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let mut ds = f.debug_struct("DeleteSuccess");
+            let mut ds = f.debug_struct("GetSuccess");
+            ds.field("nbt", &self.nbt());
             ds.finish()
         }
     }
-    pub enum ErrorResponseOffset {}
+    pub enum TryExistsOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct ErrorResponse<'a> {
+    pub struct TryExists<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for ErrorResponse<'a> {
-        type Inner = ErrorResponse<'a>;
+    impl<'a> flatbuffers::Follow<'a> for TryExists<'a> {
+        type Inner = TryExists<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -934,47 +1334,52 @@ pub mod hex_flatbuffer {
         }
     }
 
-    impl<'a> ErrorResponse<'a> {
-        pub const VT_ID: flatbuffers::VOffsetT = 4;
-        pub const VT_OTHER: flatbuffers::VOffsetT = 6;
+    impl<'a> TryExists<'a> {
+        pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 6;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            ErrorResponse { _tab: table }
+            TryExists { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args ErrorResponseArgs<'args>,
-        ) -> flatbuffers::WIPOffset<ErrorResponse<'bldr>> {
-            let mut builder = ErrorResponseBuilder::new(_fbb);
-            if let Some(x) = args.other {
-                builder.add_other(x);
+            args: &'args TryExistsArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryExists<'bldr>> {
+            let mut builder = TryExistsBuilder::new(_fbb);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
+            }
+            if let Some(x) = args.pattern {
+                builder.add_pattern(x);
             }
-            builder.add_id(args.id);
             builder.finish()
         }
 
         #[inline]
-        pub fn id(&self) -> u16 {
+        pub fn pattern(&self) -> Option<&'a str> {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
-            unsafe { self._tab.get::<u16>(ErrorResponse::VT_ID, Some(0)).unwrap() }
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryExists::VT_PATTERN, None)
+            }
         }
         #[inline]
-        pub fn other(&self) -> Option<&'a str> {
+        pub fn namespace(&self) -> Option<&'a str> {
             // Safety:
             // Created from valid Table for this object
             // which contains a valid value in this slot
             unsafe {
                 self._tab
-                    .get::<flatbuffers::ForwardsUOffset<&str>>(ErrorResponse::VT_OTHER, None)
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryExists::VT_NAMESPACE, None)
             }
         }
     }
 
-    impl flatbuffers::Verifiable for ErrorResponse<'_> {
+    impl flatbuffers::Verifiable for TryExists<'_> {
         #[inline]
         fn run_verifier(
             v: &mut flatbuffers::Verifier,
@@ -982,71 +1387,306 @@ pub mod hex_flatbuffer {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-                .visit_field::<u16>("id", Self::VT_ID, false)?
-                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("other", Self::VT_OTHER, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "pattern",
+                    Self::VT_PATTERN,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
                 .finish();
             Ok(())
         }
     }
-    pub struct ErrorResponseArgs<'a> {
-        pub id: u16,
-        pub other: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub struct TryExistsArgs<'a> {
+        pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
     }
-    impl<'a> Default for ErrorResponseArgs<'a> {
+    impl<'a> Default for TryExistsArgs<'a> {
         #[inline]
         fn default() -> Self {
-            ErrorResponseArgs { id: 0, other: None }
+            TryExistsArgs {
+                pattern: None,
+                namespace: None,
+            }
         }
     }
 
-    pub struct ErrorResponseBuilder<'a: 'b, 'b> {
+    pub struct TryExistsBuilder<'a: 'b, 'b> {
         fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> ErrorResponseBuilder<'a, 'b> {
+    impl<'a: 'b, 'b> TryExistsBuilder<'a, 'b> {
         #[inline]
-        pub fn add_id(&mut self, id: u16) {
-            self.fbb_.push_slot::<u16>(ErrorResponse::VT_ID, id, 0);
+        pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryExists::VT_PATTERN, pattern);
         }
         #[inline]
-        pub fn add_other(&mut self, other: flatbuffers::WIPOffset<&'b str>) {
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
             self.fbb_
-                .push_slot_always::<flatbuffers::WIPOffset<_>>(ErrorResponse::VT_OTHER, other);
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryExists::VT_NAMESPACE, namespace);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryExistsBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryExistsBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryExists<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryExists<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryExists");
+            ds.field("pattern", &self.pattern());
+            ds.field("namespace", &self.namespace());
+            ds.finish()
+        }
+    }
+    pub enum ExistsSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ExistsSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ExistsSuccess<'a> {
+        type Inner = ExistsSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ExistsSuccess<'a> {
+        pub const VT_EXISTS: flatbuffers::VOffsetT = 4;
+        pub const VT_EXPIRY: flatbuffers::VOffsetT = 6;
+        pub const VT_TTL_SECONDS: flatbuffers::VOffsetT = 8;
+        pub const VT_SIZE: flatbuffers::VOffsetT = 10;
+        pub const VT_IOTA_TYPE: flatbuffers::VOffsetT = 12;
+        pub const VT_SANATIZED_ENTITY: flatbuffers::VOffsetT = 14;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ExistsSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ExistsSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ExistsSuccess<'bldr>> {
+            let mut builder = ExistsSuccessBuilder::new(_fbb);
+            builder.add_size(args.size);
+            builder.add_ttl_seconds(args.ttl_seconds);
+            builder.add_expiry(args.expiry);
+            if let Some(x) = args.iota_type {
+                builder.add_iota_type(x);
+            }
+            builder.add_sanatized_entity(args.sanatized_entity);
+            builder.add_exists(args.exists);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn exists(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(ExistsSuccess::VT_EXISTS, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn expiry(&self) -> i64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i64>(ExistsSuccess::VT_EXPIRY, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn ttl_seconds(&self) -> i64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i64>(ExistsSuccess::VT_TTL_SECONDS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn size(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(ExistsSuccess::VT_SIZE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn iota_type(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(ExistsSuccess::VT_IOTA_TYPE, None)
+            }
+        }
+        #[inline]
+        pub fn sanatized_entity(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(ExistsSuccess::VT_SANATIZED_ENTITY, Some(false))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ExistsSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<bool>("exists", Self::VT_EXISTS, false)?
+                .visit_field::<i64>("expiry", Self::VT_EXPIRY, false)?
+                .visit_field::<i64>("ttl_seconds", Self::VT_TTL_SECONDS, false)?
+                .visit_field::<u64>("size", Self::VT_SIZE, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "iota_type",
+                    Self::VT_IOTA_TYPE,
+                    false,
+                )?
+                .visit_field::<bool>("sanatized_entity", Self::VT_SANATIZED_ENTITY, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ExistsSuccessArgs<'a> {
+        pub exists: bool,
+        pub expiry: i64,
+        pub ttl_seconds: i64,
+        pub size: u64,
+        pub iota_type: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub sanatized_entity: bool,
+    }
+    impl<'a> Default for ExistsSuccessArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ExistsSuccessArgs {
+                exists: false,
+                expiry: 0,
+                ttl_seconds: 0,
+                size: 0,
+                iota_type: None,
+                sanatized_entity: false,
+            }
+        }
+    }
+
+    pub struct ExistsSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ExistsSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_exists(&mut self, exists: bool) {
+            self.fbb_
+                .push_slot::<bool>(ExistsSuccess::VT_EXISTS, exists, false);
+        }
+        #[inline]
+        pub fn add_expiry(&mut self, expiry: i64) {
+            self.fbb_
+                .push_slot::<i64>(ExistsSuccess::VT_EXPIRY, expiry, 0);
+        }
+        #[inline]
+        pub fn add_ttl_seconds(&mut self, ttl_seconds: i64) {
+            self.fbb_
+                .push_slot::<i64>(ExistsSuccess::VT_TTL_SECONDS, ttl_seconds, 0);
+        }
+        #[inline]
+        pub fn add_size(&mut self, size: u64) {
+            self.fbb_.push_slot::<u64>(ExistsSuccess::VT_SIZE, size, 0);
+        }
+        #[inline]
+        pub fn add_iota_type(&mut self, iota_type: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                ExistsSuccess::VT_IOTA_TYPE,
+                iota_type,
+            );
+        }
+        #[inline]
+        pub fn add_sanatized_entity(&mut self, sanatized_entity: bool) {
+            self.fbb_.push_slot::<bool>(
+                ExistsSuccess::VT_SANATIZED_ENTITY,
+                sanatized_entity,
+                false,
+            );
         }
         #[inline]
         pub fn new(
             _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-        ) -> ErrorResponseBuilder<'a, 'b> {
+        ) -> ExistsSuccessBuilder<'a, 'b> {
             let start = _fbb.start_table();
-            ErrorResponseBuilder {
+            ExistsSuccessBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<ErrorResponse<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<ExistsSuccess<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    impl core::fmt::Debug for ErrorResponse<'_> {
+    impl core::fmt::Debug for ExistsSuccess<'_> {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            let mut ds = f.debug_struct("ErrorResponse");
-            ds.field("id", &self.id());
-            ds.field("other", &self.other());
+            let mut ds = f.debug_struct("ExistsSuccess");
+            ds.field("exists", &self.exists());
+            ds.field("expiry", &self.expiry());
+            ds.field("ttl_seconds", &self.ttl_seconds());
+            ds.field("size", &self.size());
+            ds.field("iota_type", &self.iota_type());
+            ds.field("sanatized_entity", &self.sanatized_entity());
             ds.finish()
         }
     }
-    pub enum PacketOffset {}
+    pub enum TryDeleteOffset {}
     #[derive(Copy, Clone, PartialEq)]
 
-    pub struct Packet<'a> {
+    pub struct TryDelete<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for Packet<'a> {
-        type Inner = Packet<'a>;
+    impl<'a> flatbuffers::Follow<'a> for TryDelete<'a> {
+        type Inner = TryDelete<'a>;
         #[inline]
         unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
@@ -1055,60 +1695,3861 @@ pub mod hex_flatbuffer {
         }
     }
 
-    impl<'a> Packet<'a> {
-        pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 4;
-        pub const VT_DATA: flatbuffers::VOffsetT = 6;
+    impl<'a> TryDelete<'a> {
+        pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
+        pub const VT_PASSWORD: flatbuffers::VOffsetT = 6;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 8;
 
         #[inline]
         pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            Packet { _tab: table }
+            TryDelete { _tab: table }
         }
         #[allow(unused_mut)]
         pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
             _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args PacketArgs,
-        ) -> flatbuffers::WIPOffset<Packet<'bldr>> {
-            let mut builder = PacketBuilder::new(_fbb);
-            if let Some(x) = args.data {
-                builder.add_data(x);
+            args: &'args TryDeleteArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryDelete<'bldr>> {
+            let mut builder = TryDeleteBuilder::new(_fbb);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
+            }
+            if let Some(x) = args.password {
+                builder.add_password(x);
+            }
+            if let Some(x) = args.pattern {
+                builder.add_pattern(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn pattern(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryDelete::VT_PATTERN, None)
+            }
+        }
+        #[inline]
+        pub fn password(&self) -> Option<&'a FlatbufferMoment> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<FlatbufferMoment>(TryDelete::VT_PASSWORD, None)
+            }
+        }
+        #[inline]
+        pub fn namespace(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryDelete::VT_NAMESPACE, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryDelete<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "pattern",
+                    Self::VT_PATTERN,
+                    false,
+                )?
+                .visit_field::<FlatbufferMoment>("password", Self::VT_PASSWORD, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryDeleteArgs<'a> {
+        pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub password: Option<&'a FlatbufferMoment>,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TryDeleteArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryDeleteArgs {
+                pattern: None,
+                password: None,
+                namespace: None,
+            }
+        }
+    }
+
+    pub struct TryDeleteBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryDeleteBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryDelete::VT_PATTERN, pattern);
+        }
+        #[inline]
+        pub fn add_password(&mut self, password: &FlatbufferMoment) {
+            self.fbb_
+                .push_slot_always::<&FlatbufferMoment>(TryDelete::VT_PASSWORD, password);
+        }
+        #[inline]
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryDelete::VT_NAMESPACE, namespace);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryDeleteBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryDeleteBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryDelete<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryDelete<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryDelete");
+            ds.field("pattern", &self.pattern());
+            ds.field("password", &self.password());
+            ds.field("namespace", &self.namespace());
+            ds.finish()
+        }
+    }
+    pub enum DeleteSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct DeleteSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for DeleteSuccess<'a> {
+        type Inner = DeleteSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> DeleteSuccess<'a> {
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            DeleteSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            _args: &'args DeleteSuccessArgs,
+        ) -> flatbuffers::WIPOffset<DeleteSuccess<'bldr>> {
+            let mut builder = DeleteSuccessBuilder::new(_fbb);
+            builder.finish()
+        }
+    }
+
+    impl flatbuffers::Verifiable for DeleteSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?.finish();
+            Ok(())
+        }
+    }
+    pub struct DeleteSuccessArgs {}
+    impl<'a> Default for DeleteSuccessArgs {
+        #[inline]
+        fn default() -> Self {
+            DeleteSuccessArgs {}
+        }
+    }
+
+    pub struct DeleteSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> DeleteSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> DeleteSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            DeleteSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<DeleteSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for DeleteSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("DeleteSuccess");
+            ds.finish()
+        }
+    }
+    pub enum TryReplaceOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryReplace<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryReplace<'a> {
+        type Inner = TryReplace<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryReplace<'a> {
+        pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
+        pub const VT_PASSWORD: flatbuffers::VOffsetT = 6;
+        pub const VT_NBT: flatbuffers::VOffsetT = 8;
+        pub const VT_KEEP_DELETION: flatbuffers::VOffsetT = 10;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 12;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryReplace { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryReplaceArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryReplace<'bldr>> {
+            let mut builder = TryReplaceBuilder::new(_fbb);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
+            }
+            if let Some(x) = args.nbt {
+                builder.add_nbt(x);
+            }
+            if let Some(x) = args.password {
+                builder.add_password(x);
+            }
+            if let Some(x) = args.pattern {
+                builder.add_pattern(x);
+            }
+            builder.add_keep_deletion(args.keep_deletion);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn pattern(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryReplace::VT_PATTERN, None)
+            }
+        }
+        #[inline]
+        pub fn password(&self) -> Option<&'a FlatbufferMoment> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<FlatbufferMoment>(TryReplace::VT_PASSWORD, None)
+            }
+        }
+        #[inline]
+        pub fn nbt(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        TryReplace::VT_NBT,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn keep_deletion(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(TryReplace::VT_KEEP_DELETION, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn namespace(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryReplace::VT_NAMESPACE, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryReplace<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "pattern",
+                    Self::VT_PATTERN,
+                    false,
+                )?
+                .visit_field::<FlatbufferMoment>("password", Self::VT_PASSWORD, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "nbt",
+                    Self::VT_NBT,
+                    false,
+                )?
+                .visit_field::<bool>("keep_deletion", Self::VT_KEEP_DELETION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryReplaceArgs<'a> {
+        pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub password: Option<&'a FlatbufferMoment>,
+        pub nbt: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub keep_deletion: bool,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TryReplaceArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryReplaceArgs {
+                pattern: None,
+                password: None,
+                nbt: None,
+                keep_deletion: false,
+                namespace: None,
+            }
+        }
+    }
+
+    pub struct TryReplaceBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryReplaceBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryReplace::VT_PATTERN, pattern);
+        }
+        #[inline]
+        pub fn add_password(&mut self, password: &FlatbufferMoment) {
+            self.fbb_
+                .push_slot_always::<&FlatbufferMoment>(TryReplace::VT_PASSWORD, password);
+        }
+        #[inline]
+        pub fn add_nbt(&mut self, nbt: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryReplace::VT_NBT, nbt);
+        }
+        #[inline]
+        pub fn add_keep_deletion(&mut self, keep_deletion: bool) {
+            self.fbb_
+                .push_slot::<bool>(TryReplace::VT_KEEP_DELETION, keep_deletion, false);
+        }
+        #[inline]
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryReplace::VT_NAMESPACE, namespace);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryReplaceBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryReplaceBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryReplace<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryReplace<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryReplace");
+            ds.field("pattern", &self.pattern());
+            ds.field("password", &self.password());
+            ds.field("nbt", &self.nbt());
+            ds.field("keep_deletion", &self.keep_deletion());
+            ds.field("namespace", &self.namespace());
+            ds.finish()
+        }
+    }
+    pub enum ReplaceSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ReplaceSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ReplaceSuccess<'a> {
+        type Inner = ReplaceSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ReplaceSuccess<'a> {
+        pub const VT_SANATIZED_ENTITY: flatbuffers::VOffsetT = 4;
+        pub const VT_STRIPPED_ITEMS: flatbuffers::VOffsetT = 6;
+        pub const VT_REPORT: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ReplaceSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ReplaceSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ReplaceSuccess<'bldr>> {
+            let mut builder = ReplaceSuccessBuilder::new(_fbb);
+            if let Some(x) = args.report {
+                builder.add_report(x);
+            }
+            if let Some(x) = args.stripped_items {
+                builder.add_stripped_items(x);
+            }
+            builder.add_sanatized_entity(args.sanatized_entity);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn sanatized_entity(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(ReplaceSuccess::VT_SANATIZED_ENTITY, Some(false))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn stripped_items(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(ReplaceSuccess::VT_STRIPPED_ITEMS, None)
+            }
+        }
+        #[inline]
+        pub fn report(&self) -> Option<SanitizeReport<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<SanitizeReport>>(
+                        ReplaceSuccess::VT_REPORT,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ReplaceSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<bool>("sanatized_entity", Self::VT_SANATIZED_ENTITY, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("stripped_items", Self::VT_STRIPPED_ITEMS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<SanitizeReport>>(
+                    "report",
+                    Self::VT_REPORT,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ReplaceSuccessArgs<'a> {
+        pub sanatized_entity: bool,
+        pub stripped_items: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub report: Option<flatbuffers::WIPOffset<SanitizeReport<'a>>>,
+    }
+    impl<'a> Default for ReplaceSuccessArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ReplaceSuccessArgs {
+                sanatized_entity: false,
+                stripped_items: None,
+                report: None,
+            }
+        }
+    }
+
+    pub struct ReplaceSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ReplaceSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_sanatized_entity(&mut self, sanatized_entity: bool) {
+            self.fbb_.push_slot::<bool>(
+                ReplaceSuccess::VT_SANATIZED_ENTITY,
+                sanatized_entity,
+                false,
+            );
+        }
+        #[inline]
+        pub fn add_stripped_items(
+            &mut self,
+            stripped_items: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                ReplaceSuccess::VT_STRIPPED_ITEMS,
+                stripped_items,
+            );
+        }
+        #[inline]
+        pub fn add_report(&mut self, report: flatbuffers::WIPOffset<SanitizeReport<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<SanitizeReport>>(
+                    ReplaceSuccess::VT_REPORT,
+                    report,
+                );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> ReplaceSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            ReplaceSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ReplaceSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ReplaceSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ReplaceSuccess");
+            ds.field("sanatized_entity", &self.sanatized_entity());
+            ds.field("stripped_items", &self.stripped_items());
+            ds.field("report", &self.report());
+            ds.finish()
+        }
+    }
+    pub enum TryRefreshOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryRefresh<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryRefresh<'a> {
+        type Inner = TryRefresh<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryRefresh<'a> {
+        pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
+        pub const VT_PASSWORD: flatbuffers::VOffsetT = 6;
+        pub const VT_TTL_SECONDS: flatbuffers::VOffsetT = 8;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryRefresh { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryRefreshArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryRefresh<'bldr>> {
+            let mut builder = TryRefreshBuilder::new(_fbb);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
+            }
+            builder.add_ttl_seconds(args.ttl_seconds);
+            if let Some(x) = args.password {
+                builder.add_password(x);
+            }
+            if let Some(x) = args.pattern {
+                builder.add_pattern(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn pattern(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryRefresh::VT_PATTERN, None)
+            }
+        }
+        #[inline]
+        pub fn password(&self) -> Option<&'a FlatbufferMoment> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<FlatbufferMoment>(TryRefresh::VT_PASSWORD, None)
+            }
+        }
+        #[inline]
+        pub fn ttl_seconds(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TryRefresh::VT_TTL_SECONDS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn namespace(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryRefresh::VT_NAMESPACE, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryRefresh<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "pattern",
+                    Self::VT_PATTERN,
+                    false,
+                )?
+                .visit_field::<FlatbufferMoment>("password", Self::VT_PASSWORD, false)?
+                .visit_field::<u32>("ttl_seconds", Self::VT_TTL_SECONDS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryRefreshArgs<'a> {
+        pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub password: Option<&'a FlatbufferMoment>,
+        pub ttl_seconds: u32,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TryRefreshArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryRefreshArgs {
+                pattern: None,
+                password: None,
+                ttl_seconds: 0,
+                namespace: None,
+            }
+        }
+    }
+
+    pub struct TryRefreshBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryRefreshBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryRefresh::VT_PATTERN, pattern);
+        }
+        #[inline]
+        pub fn add_password(&mut self, password: &FlatbufferMoment) {
+            self.fbb_
+                .push_slot_always::<&FlatbufferMoment>(TryRefresh::VT_PASSWORD, password);
+        }
+        #[inline]
+        pub fn add_ttl_seconds(&mut self, ttl_seconds: u32) {
+            self.fbb_
+                .push_slot::<u32>(TryRefresh::VT_TTL_SECONDS, ttl_seconds, 0);
+        }
+        #[inline]
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryRefresh::VT_NAMESPACE, namespace);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryRefreshBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryRefreshBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryRefresh<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryRefresh<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryRefresh");
+            ds.field("pattern", &self.pattern());
+            ds.field("password", &self.password());
+            ds.field("ttl_seconds", &self.ttl_seconds());
+            ds.field("namespace", &self.namespace());
+            ds.finish()
+        }
+    }
+    pub enum RefreshSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct RefreshSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for RefreshSuccess<'a> {
+        type Inner = RefreshSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> RefreshSuccess<'a> {
+        pub const VT_EXPIRY: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            RefreshSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args RefreshSuccessArgs,
+        ) -> flatbuffers::WIPOffset<RefreshSuccess<'bldr>> {
+            let mut builder = RefreshSuccessBuilder::new(_fbb);
+            builder.add_expiry(args.expiry);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn expiry(&self) -> i64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i64>(RefreshSuccess::VT_EXPIRY, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for RefreshSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<i64>("expiry", Self::VT_EXPIRY, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct RefreshSuccessArgs {
+        pub expiry: i64,
+    }
+    impl<'a> Default for RefreshSuccessArgs {
+        #[inline]
+        fn default() -> Self {
+            RefreshSuccessArgs { expiry: 0 }
+        }
+    }
+
+    pub struct RefreshSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> RefreshSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_expiry(&mut self, expiry: i64) {
+            self.fbb_
+                .push_slot::<i64>(RefreshSuccess::VT_EXPIRY, expiry, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> RefreshSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            RefreshSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<RefreshSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for RefreshSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("RefreshSuccess");
+            ds.field("expiry", &self.expiry());
+            ds.finish()
+        }
+    }
+    pub enum FutureHandleOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct FutureHandle<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for FutureHandle<'a> {
+        type Inner = FutureHandle<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> FutureHandle<'a> {
+        pub const VT_INDEX: flatbuffers::VOffsetT = 4;
+        pub const VT_UUID: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            FutureHandle { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args FutureHandleArgs<'args>,
+        ) -> flatbuffers::WIPOffset<FutureHandle<'bldr>> {
+            let mut builder = FutureHandleBuilder::new(_fbb);
+            if let Some(x) = args.uuid {
+                builder.add_uuid(x);
+            }
+            builder.add_index(args.index);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn index(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(FutureHandle::VT_INDEX, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn uuid(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        FutureHandle::VT_UUID,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for FutureHandle<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("index", Self::VT_INDEX, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "uuid",
+                    Self::VT_UUID,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct FutureHandleArgs<'a> {
+        pub index: u32,
+        pub uuid: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for FutureHandleArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            FutureHandleArgs {
+                index: 0,
+                uuid: None,
+            }
+        }
+    }
+
+    pub struct FutureHandleBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> FutureHandleBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_index(&mut self, index: u32) {
+            self.fbb_.push_slot::<u32>(FutureHandle::VT_INDEX, index, 0);
+        }
+        #[inline]
+        pub fn add_uuid(&mut self, uuid: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(FutureHandle::VT_UUID, uuid);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> FutureHandleBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            FutureHandleBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<FutureHandle<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for FutureHandle<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("FutureHandle");
+            ds.field("index", &self.index());
+            ds.field("uuid", &self.uuid());
+            ds.finish()
+        }
+    }
+    pub enum IotaOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Iota<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Iota<'a> {
+        type Inner = Iota<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Iota<'a> {
+        pub const VT_NBT: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Iota { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args IotaArgs<'args>,
+        ) -> flatbuffers::WIPOffset<Iota<'bldr>> {
+            let mut builder = IotaBuilder::new(_fbb);
+            if let Some(x) = args.nbt {
+                builder.add_nbt(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn nbt(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        Iota::VT_NBT,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Iota<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "nbt",
+                    Self::VT_NBT,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct IotaArgs<'a> {
+        pub nbt: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    }
+    impl<'a> Default for IotaArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            IotaArgs { nbt: None }
+        }
+    }
+
+    pub struct IotaBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> IotaBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_nbt(&mut self, nbt: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(Iota::VT_NBT, nbt);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> IotaBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            IotaBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Iota<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for Iota<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("Iota");
+            ds.field("nbt", &self.nbt());
+            ds.finish()
+        }
+    }
+    pub enum TryCreateFutureOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryCreateFuture<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryCreateFuture<'a> {
+        type Inner = TryCreateFuture<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryCreateFuture<'a> {
+        pub const VT_TTL_SECONDS: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryCreateFuture { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryCreateFutureArgs,
+        ) -> flatbuffers::WIPOffset<TryCreateFuture<'bldr>> {
+            let mut builder = TryCreateFutureBuilder::new(_fbb);
+            builder.add_ttl_seconds(args.ttl_seconds);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn ttl_seconds(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TryCreateFuture::VT_TTL_SECONDS, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryCreateFuture<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u32>("ttl_seconds", Self::VT_TTL_SECONDS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryCreateFutureArgs {
+        pub ttl_seconds: u32,
+    }
+    impl<'a> Default for TryCreateFutureArgs {
+        #[inline]
+        fn default() -> Self {
+            TryCreateFutureArgs { ttl_seconds: 0 }
+        }
+    }
+
+    pub struct TryCreateFutureBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryCreateFutureBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_ttl_seconds(&mut self, ttl_seconds: u32) {
+            self.fbb_
+                .push_slot::<u32>(TryCreateFuture::VT_TTL_SECONDS, ttl_seconds, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> TryCreateFutureBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryCreateFutureBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryCreateFuture<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryCreateFuture<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryCreateFuture");
+            ds.field("ttl_seconds", &self.ttl_seconds());
+            ds.finish()
+        }
+    }
+    pub enum CreateFutureSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct CreateFutureSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for CreateFutureSuccess<'a> {
+        type Inner = CreateFutureSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> CreateFutureSuccess<'a> {
+        pub const VT_HANDLE: flatbuffers::VOffsetT = 4;
+        pub const VT_PASSWORD: flatbuffers::VOffsetT = 6;
+        pub const VT_EXPIRY: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            CreateFutureSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args CreateFutureSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<CreateFutureSuccess<'bldr>> {
+            let mut builder = CreateFutureSuccessBuilder::new(_fbb);
+            builder.add_expiry(args.expiry);
+            if let Some(x) = args.password {
+                builder.add_password(x);
+            }
+            if let Some(x) = args.handle {
+                builder.add_handle(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn handle(&self) -> Option<FutureHandle<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<FutureHandle>>(
+                    CreateFutureSuccess::VT_HANDLE,
+                    None,
+                )
+            }
+        }
+        #[inline]
+        pub fn password(&self) -> Option<&'a FlatbufferMoment> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<FlatbufferMoment>(CreateFutureSuccess::VT_PASSWORD, None)
+            }
+        }
+        #[inline]
+        pub fn expiry(&self) -> i64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i64>(CreateFutureSuccess::VT_EXPIRY, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for CreateFutureSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<FutureHandle>>(
+                    "handle",
+                    Self::VT_HANDLE,
+                    false,
+                )?
+                .visit_field::<FlatbufferMoment>("password", Self::VT_PASSWORD, false)?
+                .visit_field::<i64>("expiry", Self::VT_EXPIRY, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct CreateFutureSuccessArgs<'a> {
+        pub handle: Option<flatbuffers::WIPOffset<FutureHandle<'a>>>,
+        pub password: Option<&'a FlatbufferMoment>,
+        pub expiry: i64,
+    }
+    impl<'a> Default for CreateFutureSuccessArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            CreateFutureSuccessArgs {
+                handle: None,
+                password: None,
+                expiry: 0,
+            }
+        }
+    }
+
+    pub struct CreateFutureSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> CreateFutureSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_handle(&mut self, handle: flatbuffers::WIPOffset<FutureHandle<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<FutureHandle>>(
+                    CreateFutureSuccess::VT_HANDLE,
+                    handle,
+                );
+        }
+        #[inline]
+        pub fn add_password(&mut self, password: &FlatbufferMoment) {
+            self.fbb_
+                .push_slot_always::<&FlatbufferMoment>(CreateFutureSuccess::VT_PASSWORD, password);
+        }
+        #[inline]
+        pub fn add_expiry(&mut self, expiry: i64) {
+            self.fbb_
+                .push_slot::<i64>(CreateFutureSuccess::VT_EXPIRY, expiry, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> CreateFutureSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            CreateFutureSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<CreateFutureSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for CreateFutureSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("CreateFutureSuccess");
+            ds.field("handle", &self.handle());
+            ds.field("password", &self.password());
+            ds.field("expiry", &self.expiry());
+            ds.finish()
+        }
+    }
+    pub enum TryCompleteFutureOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryCompleteFuture<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryCompleteFuture<'a> {
+        type Inner = TryCompleteFuture<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryCompleteFuture<'a> {
+        pub const VT_HANDLE: flatbuffers::VOffsetT = 4;
+        pub const VT_PASSWORD: flatbuffers::VOffsetT = 6;
+        pub const VT_RESULT: flatbuffers::VOffsetT = 8;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryCompleteFuture { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryCompleteFutureArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryCompleteFuture<'bldr>> {
+            let mut builder = TryCompleteFutureBuilder::new(_fbb);
+            if let Some(x) = args.result {
+                builder.add_result(x);
+            }
+            if let Some(x) = args.password {
+                builder.add_password(x);
+            }
+            if let Some(x) = args.handle {
+                builder.add_handle(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn handle(&self) -> Option<FutureHandle<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<FutureHandle>>(
+                    TryCompleteFuture::VT_HANDLE,
+                    None,
+                )
+            }
+        }
+        #[inline]
+        pub fn password(&self) -> Option<&'a FlatbufferMoment> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<FlatbufferMoment>(TryCompleteFuture::VT_PASSWORD, None)
+            }
+        }
+        #[inline]
+        pub fn result(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Iota<'a>>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Iota>>,
+                >>(TryCompleteFuture::VT_RESULT, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryCompleteFuture<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<FutureHandle>>(
+                    "handle",
+                    Self::VT_HANDLE,
+                    false,
+                )?
+                .visit_field::<FlatbufferMoment>("password", Self::VT_PASSWORD, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Iota>>,
+                >>("result", Self::VT_RESULT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryCompleteFutureArgs<'a> {
+        pub handle: Option<flatbuffers::WIPOffset<FutureHandle<'a>>>,
+        pub password: Option<&'a FlatbufferMoment>,
+        pub result: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Iota<'a>>>>,
+        >,
+    }
+    impl<'a> Default for TryCompleteFutureArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryCompleteFutureArgs {
+                handle: None,
+                password: None,
+                result: None,
+            }
+        }
+    }
+
+    pub struct TryCompleteFutureBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryCompleteFutureBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_handle(&mut self, handle: flatbuffers::WIPOffset<FutureHandle<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<FutureHandle>>(
+                    TryCompleteFuture::VT_HANDLE,
+                    handle,
+                );
+        }
+        #[inline]
+        pub fn add_password(&mut self, password: &FlatbufferMoment) {
+            self.fbb_
+                .push_slot_always::<&FlatbufferMoment>(TryCompleteFuture::VT_PASSWORD, password);
+        }
+        #[inline]
+        pub fn add_result(
+            &mut self,
+            result: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Iota<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TryCompleteFuture::VT_RESULT,
+                result,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> TryCompleteFutureBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryCompleteFutureBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryCompleteFuture<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryCompleteFuture<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryCompleteFuture");
+            ds.field("handle", &self.handle());
+            ds.field("password", &self.password());
+            ds.field("result", &self.result());
+            ds.finish()
+        }
+    }
+    pub enum CompleteFutureSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct CompleteFutureSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for CompleteFutureSuccess<'a> {
+        type Inner = CompleteFutureSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> CompleteFutureSuccess<'a> {
+        pub const VT_SANATIZED_ENTITY: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            CompleteFutureSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args CompleteFutureSuccessArgs,
+        ) -> flatbuffers::WIPOffset<CompleteFutureSuccess<'bldr>> {
+            let mut builder = CompleteFutureSuccessBuilder::new(_fbb);
+            builder.add_sanatized_entity(args.sanatized_entity);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn sanatized_entity(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(CompleteFutureSuccess::VT_SANATIZED_ENTITY, Some(false))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for CompleteFutureSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<bool>("sanatized_entity", Self::VT_SANATIZED_ENTITY, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct CompleteFutureSuccessArgs {
+        pub sanatized_entity: bool,
+    }
+    impl<'a> Default for CompleteFutureSuccessArgs {
+        #[inline]
+        fn default() -> Self {
+            CompleteFutureSuccessArgs {
+                sanatized_entity: false,
+            }
+        }
+    }
+
+    pub struct CompleteFutureSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> CompleteFutureSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_sanatized_entity(&mut self, sanatized_entity: bool) {
+            self.fbb_.push_slot::<bool>(
+                CompleteFutureSuccess::VT_SANATIZED_ENTITY,
+                sanatized_entity,
+                false,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> CompleteFutureSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            CompleteFutureSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<CompleteFutureSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for CompleteFutureSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("CompleteFutureSuccess");
+            ds.field("sanatized_entity", &self.sanatized_entity());
+            ds.finish()
+        }
+    }
+    pub enum TryPollFutureOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryPollFuture<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryPollFuture<'a> {
+        type Inner = TryPollFuture<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryPollFuture<'a> {
+        pub const VT_HANDLE: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryPollFuture { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryPollFutureArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryPollFuture<'bldr>> {
+            let mut builder = TryPollFutureBuilder::new(_fbb);
+            if let Some(x) = args.handle {
+                builder.add_handle(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn handle(&self) -> Option<FutureHandle<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<FutureHandle>>(
+                    TryPollFuture::VT_HANDLE,
+                    None,
+                )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryPollFuture<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<FutureHandle>>(
+                    "handle",
+                    Self::VT_HANDLE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryPollFutureArgs<'a> {
+        pub handle: Option<flatbuffers::WIPOffset<FutureHandle<'a>>>,
+    }
+    impl<'a> Default for TryPollFutureArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryPollFutureArgs { handle: None }
+        }
+    }
+
+    pub struct TryPollFutureBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryPollFutureBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_handle(&mut self, handle: flatbuffers::WIPOffset<FutureHandle<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<FutureHandle>>(
+                    TryPollFuture::VT_HANDLE,
+                    handle,
+                );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> TryPollFutureBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryPollFutureBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryPollFuture<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryPollFuture<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryPollFuture");
+            ds.field("handle", &self.handle());
+            ds.finish()
+        }
+    }
+    pub enum PollFutureSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct PollFutureSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for PollFutureSuccess<'a> {
+        type Inner = PollFutureSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> PollFutureSuccess<'a> {
+        pub const VT_EXITED: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            PollFutureSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args PollFutureSuccessArgs,
+        ) -> flatbuffers::WIPOffset<PollFutureSuccess<'bldr>> {
+            let mut builder = PollFutureSuccessBuilder::new(_fbb);
+            builder.add_exited(args.exited);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn exited(&self) -> bool {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<bool>(PollFutureSuccess::VT_EXITED, Some(false))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for PollFutureSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<bool>("exited", Self::VT_EXITED, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct PollFutureSuccessArgs {
+        pub exited: bool,
+    }
+    impl<'a> Default for PollFutureSuccessArgs {
+        #[inline]
+        fn default() -> Self {
+            PollFutureSuccessArgs { exited: false }
+        }
+    }
+
+    pub struct PollFutureSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> PollFutureSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_exited(&mut self, exited: bool) {
+            self.fbb_
+                .push_slot::<bool>(PollFutureSuccess::VT_EXITED, exited, false);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> PollFutureSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            PollFutureSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<PollFutureSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for PollFutureSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("PollFutureSuccess");
+            ds.field("exited", &self.exited());
+            ds.finish()
+        }
+    }
+    pub enum TryFetchFutureOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryFetchFuture<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryFetchFuture<'a> {
+        type Inner = TryFetchFuture<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryFetchFuture<'a> {
+        pub const VT_HANDLE: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryFetchFuture { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryFetchFutureArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryFetchFuture<'bldr>> {
+            let mut builder = TryFetchFutureBuilder::new(_fbb);
+            if let Some(x) = args.handle {
+                builder.add_handle(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn handle(&self) -> Option<FutureHandle<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<FutureHandle>>(
+                    TryFetchFuture::VT_HANDLE,
+                    None,
+                )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryFetchFuture<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<FutureHandle>>(
+                    "handle",
+                    Self::VT_HANDLE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryFetchFutureArgs<'a> {
+        pub handle: Option<flatbuffers::WIPOffset<FutureHandle<'a>>>,
+    }
+    impl<'a> Default for TryFetchFutureArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryFetchFutureArgs { handle: None }
+        }
+    }
+
+    pub struct TryFetchFutureBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryFetchFutureBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_handle(&mut self, handle: flatbuffers::WIPOffset<FutureHandle<'b>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<FutureHandle>>(
+                    TryFetchFuture::VT_HANDLE,
+                    handle,
+                );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> TryFetchFutureBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryFetchFutureBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryFetchFuture<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryFetchFuture<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryFetchFuture");
+            ds.field("handle", &self.handle());
+            ds.finish()
+        }
+    }
+    pub enum FetchFutureSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct FetchFutureSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for FetchFutureSuccess<'a> {
+        type Inner = FetchFutureSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> FetchFutureSuccess<'a> {
+        pub const VT_RESULT: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            FetchFutureSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args FetchFutureSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<FetchFutureSuccess<'bldr>> {
+            let mut builder = FetchFutureSuccessBuilder::new(_fbb);
+            if let Some(x) = args.result {
+                builder.add_result(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn result(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Iota<'a>>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Iota>>,
+                >>(FetchFutureSuccess::VT_RESULT, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for FetchFutureSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Iota>>,
+                >>("result", Self::VT_RESULT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct FetchFutureSuccessArgs<'a> {
+        pub result: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Iota<'a>>>>,
+        >,
+    }
+    impl<'a> Default for FetchFutureSuccessArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            FetchFutureSuccessArgs { result: None }
+        }
+    }
+
+    pub struct FetchFutureSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> FetchFutureSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_result(
+            &mut self,
+            result: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Iota<'b>>>,
+            >,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                FetchFutureSuccess::VT_RESULT,
+                result,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> FetchFutureSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            FetchFutureSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<FetchFutureSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for FetchFutureSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("FetchFutureSuccess");
+            ds.field("result", &self.result());
+            ds.finish()
+        }
+    }
+    pub enum TryPushItemOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryPushItem<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryPushItem<'a> {
+        type Inner = TryPushItem<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryPushItem<'a> {
+        pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
+        pub const VT_ITEM: flatbuffers::VOffsetT = 6;
+        pub const VT_COUNT: flatbuffers::VOffsetT = 8;
+        pub const VT_TTL_SECONDS: flatbuffers::VOffsetT = 10;
+        pub const VT_TAGS: flatbuffers::VOffsetT = 12;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 14;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryPushItem { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryPushItemArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryPushItem<'bldr>> {
+            let mut builder = TryPushItemBuilder::new(_fbb);
+            builder.add_count(args.count);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
+            }
+            if let Some(x) = args.tags {
+                builder.add_tags(x);
+            }
+            builder.add_ttl_seconds(args.ttl_seconds);
+            if let Some(x) = args.item {
+                builder.add_item(x);
+            }
+            if let Some(x) = args.pattern {
+                builder.add_pattern(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn pattern(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryPushItem::VT_PATTERN, None)
+            }
+        }
+        #[inline]
+        pub fn item(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        TryPushItem::VT_ITEM,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn count(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(TryPushItem::VT_COUNT, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn ttl_seconds(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(TryPushItem::VT_TTL_SECONDS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn tags(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(TryPushItem::VT_TAGS, None)
+            }
+        }
+        #[inline]
+        pub fn namespace(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryPushItem::VT_NAMESPACE, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryPushItem<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "pattern",
+                    Self::VT_PATTERN,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "item",
+                    Self::VT_ITEM,
+                    false,
+                )?
+                .visit_field::<u64>("count", Self::VT_COUNT, false)?
+                .visit_field::<u32>("ttl_seconds", Self::VT_TTL_SECONDS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("tags", Self::VT_TAGS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryPushItemArgs<'a> {
+        pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub item: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub count: u64,
+        pub ttl_seconds: u32,
+        pub tags: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TryPushItemArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryPushItemArgs {
+                pattern: None,
+                item: None,
+                count: 0,
+                ttl_seconds: 0,
+                tags: None,
+                namespace: None,
+            }
+        }
+    }
+
+    pub struct TryPushItemBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryPushItemBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPushItem::VT_PATTERN, pattern);
+        }
+        #[inline]
+        pub fn add_item(&mut self, item: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPushItem::VT_ITEM, item);
+        }
+        #[inline]
+        pub fn add_count(&mut self, count: u64) {
+            self.fbb_.push_slot::<u64>(TryPushItem::VT_COUNT, count, 0);
+        }
+        #[inline]
+        pub fn add_ttl_seconds(&mut self, ttl_seconds: u32) {
+            self.fbb_
+                .push_slot::<u32>(TryPushItem::VT_TTL_SECONDS, ttl_seconds, 0);
+        }
+        #[inline]
+        pub fn add_tags(
+            &mut self,
+            tags: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPushItem::VT_TAGS, tags);
+        }
+        #[inline]
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TryPushItem::VT_NAMESPACE,
+                namespace,
+            );
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryPushItemBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryPushItemBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryPushItem<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryPushItem<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryPushItem");
+            ds.field("pattern", &self.pattern());
+            ds.field("item", &self.item());
+            ds.field("count", &self.count());
+            ds.field("ttl_seconds", &self.ttl_seconds());
+            ds.field("tags", &self.tags());
+            ds.field("namespace", &self.namespace());
+            ds.finish()
+        }
+    }
+    pub enum PushItemSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct PushItemSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for PushItemSuccess<'a> {
+        type Inner = PushItemSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> PushItemSuccess<'a> {
+        pub const VT_EXPIRY: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            PushItemSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args PushItemSuccessArgs,
+        ) -> flatbuffers::WIPOffset<PushItemSuccess<'bldr>> {
+            let mut builder = PushItemSuccessBuilder::new(_fbb);
+            builder.add_expiry(args.expiry);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn expiry(&self) -> i64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i64>(PushItemSuccess::VT_EXPIRY, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for PushItemSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<i64>("expiry", Self::VT_EXPIRY, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct PushItemSuccessArgs {
+        pub expiry: i64,
+    }
+    impl<'a> Default for PushItemSuccessArgs {
+        #[inline]
+        fn default() -> Self {
+            PushItemSuccessArgs { expiry: 0 }
+        }
+    }
+
+    pub struct PushItemSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> PushItemSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_expiry(&mut self, expiry: i64) {
+            self.fbb_
+                .push_slot::<i64>(PushItemSuccess::VT_EXPIRY, expiry, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> PushItemSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            PushItemSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<PushItemSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for PushItemSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("PushItemSuccess");
+            ds.field("expiry", &self.expiry());
+            ds.finish()
+        }
+    }
+    pub enum TryPullItemOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryPullItem<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryPullItem<'a> {
+        type Inner = TryPullItem<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryPullItem<'a> {
+        pub const VT_PATTERN: flatbuffers::VOffsetT = 4;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryPullItem { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryPullItemArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryPullItem<'bldr>> {
+            let mut builder = TryPullItemBuilder::new(_fbb);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
+            }
+            if let Some(x) = args.pattern {
+                builder.add_pattern(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn pattern(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryPullItem::VT_PATTERN, None)
+            }
+        }
+        #[inline]
+        pub fn namespace(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryPullItem::VT_NAMESPACE, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryPullItem<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "pattern",
+                    Self::VT_PATTERN,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryPullItemArgs<'a> {
+        pub pattern: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TryPullItemArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryPullItemArgs {
+                pattern: None,
+                namespace: None,
+            }
+        }
+    }
+
+    pub struct TryPullItemBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryPullItemBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_pattern(&mut self, pattern: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryPullItem::VT_PATTERN, pattern);
+        }
+        #[inline]
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                TryPullItem::VT_NAMESPACE,
+                namespace,
+            );
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryPullItemBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryPullItemBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryPullItem<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryPullItem<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryPullItem");
+            ds.field("pattern", &self.pattern());
+            ds.field("namespace", &self.namespace());
+            ds.finish()
+        }
+    }
+    pub enum PullItemSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct PullItemSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for PullItemSuccess<'a> {
+        type Inner = PullItemSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> PullItemSuccess<'a> {
+        pub const VT_ITEM: flatbuffers::VOffsetT = 4;
+        pub const VT_COUNT: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            PullItemSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args PullItemSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<PullItemSuccess<'bldr>> {
+            let mut builder = PullItemSuccessBuilder::new(_fbb);
+            builder.add_count(args.count);
+            if let Some(x) = args.item {
+                builder.add_item(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn item(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                        PullItemSuccess::VT_ITEM,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        pub fn count(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(PullItemSuccess::VT_COUNT, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for PullItemSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                    "item",
+                    Self::VT_ITEM,
+                    false,
+                )?
+                .visit_field::<u64>("count", Self::VT_COUNT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct PullItemSuccessArgs<'a> {
+        pub item: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+        pub count: u64,
+    }
+    impl<'a> Default for PullItemSuccessArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            PullItemSuccessArgs {
+                item: None,
+                count: 0,
+            }
+        }
+    }
+
+    pub struct PullItemSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> PullItemSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_item(&mut self, item: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(PullItemSuccess::VT_ITEM, item);
+        }
+        #[inline]
+        pub fn add_count(&mut self, count: u64) {
+            self.fbb_
+                .push_slot::<u64>(PullItemSuccess::VT_COUNT, count, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> PullItemSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            PullItemSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<PullItemSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for PullItemSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("PullItemSuccess");
+            ds.field("item", &self.item());
+            ds.field("count", &self.count());
+            ds.finish()
+        }
+    }
+    pub enum TryListOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryList<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryList<'a> {
+        type Inner = TryList<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryList<'a> {
+        pub const VT_PREFIX: flatbuffers::VOffsetT = 4;
+        pub const VT_LIMIT: flatbuffers::VOffsetT = 6;
+        pub const VT_CURSOR: flatbuffers::VOffsetT = 8;
+        pub const VT_NAMESPACE: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryList { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryListArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryList<'bldr>> {
+            let mut builder = TryListBuilder::new(_fbb);
+            if let Some(x) = args.namespace {
+                builder.add_namespace(x);
+            }
+            if let Some(x) = args.cursor {
+                builder.add_cursor(x);
+            }
+            builder.add_limit(args.limit);
+            if let Some(x) = args.prefix {
+                builder.add_prefix(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn prefix(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryList::VT_PREFIX, None)
+            }
+        }
+        #[inline]
+        pub fn limit(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(TryList::VT_LIMIT, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn cursor(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryList::VT_CURSOR, None)
+            }
+        }
+        #[inline]
+        pub fn namespace(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TryList::VT_NAMESPACE, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryList<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "prefix",
+                    Self::VT_PREFIX,
+                    false,
+                )?
+                .visit_field::<u32>("limit", Self::VT_LIMIT, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "cursor",
+                    Self::VT_CURSOR,
+                    false,
+                )?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "namespace",
+                    Self::VT_NAMESPACE,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryListArgs<'a> {
+        pub prefix: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub limit: u32,
+        pub cursor: Option<flatbuffers::WIPOffset<&'a str>>,
+        pub namespace: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TryListArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryListArgs {
+                prefix: None,
+                limit: 0,
+                cursor: None,
+                namespace: None,
+            }
+        }
+    }
+
+    pub struct TryListBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryListBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_prefix(&mut self, prefix: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryList::VT_PREFIX, prefix);
+        }
+        #[inline]
+        pub fn add_limit(&mut self, limit: u32) {
+            self.fbb_.push_slot::<u32>(TryList::VT_LIMIT, limit, 0);
+        }
+        #[inline]
+        pub fn add_cursor(&mut self, cursor: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryList::VT_CURSOR, cursor);
+        }
+        #[inline]
+        pub fn add_namespace(&mut self, namespace: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryList::VT_NAMESPACE, namespace);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TryListBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryListBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryList<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryList<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryList");
+            ds.field("prefix", &self.prefix());
+            ds.field("limit", &self.limit());
+            ds.field("cursor", &self.cursor());
+            ds.field("namespace", &self.namespace());
+            ds.finish()
+        }
+    }
+    pub enum ListSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ListSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ListSuccess<'a> {
+        type Inner = ListSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ListSuccess<'a> {
+        pub const VT_PATTERNS: flatbuffers::VOffsetT = 4;
+        pub const VT_NEXT_CURSOR: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ListSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ListSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ListSuccess<'bldr>> {
+            let mut builder = ListSuccessBuilder::new(_fbb);
+            if let Some(x) = args.next_cursor {
+                builder.add_next_cursor(x);
+            }
+            if let Some(x) = args.patterns {
+                builder.add_patterns(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn patterns(
+            &self,
+        ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+                >>(ListSuccess::VT_PATTERNS, None)
+            }
+        }
+        #[inline]
+        pub fn next_cursor(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(ListSuccess::VT_NEXT_CURSOR, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ListSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+                >>("patterns", Self::VT_PATTERNS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "next_cursor",
+                    Self::VT_NEXT_CURSOR,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ListSuccessArgs<'a> {
+        pub patterns: Option<
+            flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+        >,
+        pub next_cursor: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for ListSuccessArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ListSuccessArgs {
+                patterns: None,
+                next_cursor: None,
+            }
+        }
+    }
+
+    pub struct ListSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ListSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_patterns(
+            &mut self,
+            patterns: flatbuffers::WIPOffset<
+                flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+            >,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ListSuccess::VT_PATTERNS, patterns);
+        }
+        #[inline]
+        pub fn add_next_cursor(&mut self, next_cursor: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                ListSuccess::VT_NEXT_CURSOR,
+                next_cursor,
+            );
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ListSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            ListSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ListSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ListSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ListSuccess");
+            ds.field("patterns", &self.patterns());
+            ds.field("next_cursor", &self.next_cursor());
+            ds.finish()
+        }
+    }
+    pub enum TryHandshakeOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TryHandshake<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TryHandshake<'a> {
+        type Inner = TryHandshake<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TryHandshake<'a> {
+        pub const VT_VERSIONS: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TryHandshake { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args TryHandshakeArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TryHandshake<'bldr>> {
+            let mut builder = TryHandshakeBuilder::new(_fbb);
+            if let Some(x) = args.versions {
+                builder.add_versions(x);
+            }
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn versions(&self) -> Option<flatbuffers::Vector<'a, i16>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i16>>>(
+                        TryHandshake::VT_VERSIONS,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TryHandshake<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i16>>>(
+                    "versions",
+                    Self::VT_VERSIONS,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TryHandshakeArgs<'a> {
+        pub versions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i16>>>,
+    }
+    impl<'a> Default for TryHandshakeArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TryHandshakeArgs { versions: None }
+        }
+    }
+
+    pub struct TryHandshakeBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> TryHandshakeBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_versions(
+            &mut self,
+            versions: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i16>>,
+        ) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TryHandshake::VT_VERSIONS, versions);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> TryHandshakeBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            TryHandshakeBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TryHandshake<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TryHandshake<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TryHandshake");
+            ds.field("versions", &self.versions());
+            ds.finish()
+        }
+    }
+    pub enum HandshakeSuccessOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct HandshakeSuccess<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for HandshakeSuccess<'a> {
+        type Inner = HandshakeSuccess<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> HandshakeSuccess<'a> {
+        pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+        pub const VT_SUPPORTED_VERSIONS: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            HandshakeSuccess { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args HandshakeSuccessArgs<'args>,
+        ) -> flatbuffers::WIPOffset<HandshakeSuccess<'bldr>> {
+            let mut builder = HandshakeSuccessBuilder::new(_fbb);
+            if let Some(x) = args.supported_versions {
+                builder.add_supported_versions(x);
+            }
+            builder.add_version(args.version);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn version(&self) -> i16 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<i16>(HandshakeSuccess::VT_VERSION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn supported_versions(&self) -> Option<flatbuffers::Vector<'a, i16>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i16>>>(
+                        HandshakeSuccess::VT_SUPPORTED_VERSIONS,
+                        None,
+                    )
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for HandshakeSuccess<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<i16>("version", Self::VT_VERSION, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i16>>>(
+                    "supported_versions",
+                    Self::VT_SUPPORTED_VERSIONS,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct HandshakeSuccessArgs<'a> {
+        pub version: i16,
+        pub supported_versions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i16>>>,
+    }
+    impl<'a> Default for HandshakeSuccessArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            HandshakeSuccessArgs {
+                version: 0,
+                supported_versions: None,
+            }
+        }
+    }
+
+    pub struct HandshakeSuccessBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> HandshakeSuccessBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_version(&mut self, version: i16) {
+            self.fbb_
+                .push_slot::<i16>(HandshakeSuccess::VT_VERSION, version, 0);
+        }
+        #[inline]
+        pub fn add_supported_versions(
+            &mut self,
+            supported_versions: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i16>>,
+        ) {
+            self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+                HandshakeSuccess::VT_SUPPORTED_VERSIONS,
+                supported_versions,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> HandshakeSuccessBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            HandshakeSuccessBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<HandshakeSuccess<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for HandshakeSuccess<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("HandshakeSuccess");
+            ds.field("version", &self.version());
+            ds.field("supported_versions", &self.supported_versions());
+            ds.finish()
+        }
+    }
+    pub enum ErrorResponseOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ErrorResponse<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ErrorResponse<'a> {
+        type Inner = ErrorResponse<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ErrorResponse<'a> {
+        pub const VT_ID: flatbuffers::VOffsetT = 4;
+        pub const VT_OTHER: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ErrorResponse { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args ErrorResponseArgs<'args>,
+        ) -> flatbuffers::WIPOffset<ErrorResponse<'bldr>> {
+            let mut builder = ErrorResponseBuilder::new(_fbb);
+            if let Some(x) = args.other {
+                builder.add_other(x);
+            }
+            builder.add_id(args.id);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn id(&self) -> u16 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u16>(ErrorResponse::VT_ID, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn other(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(ErrorResponse::VT_OTHER, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ErrorResponse<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u16>("id", Self::VT_ID, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>("other", Self::VT_OTHER, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ErrorResponseArgs<'a> {
+        pub id: u16,
+        pub other: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for ErrorResponseArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            ErrorResponseArgs { id: 0, other: None }
+        }
+    }
+
+    pub struct ErrorResponseBuilder<'a: 'b, 'b> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b> ErrorResponseBuilder<'a, 'b> {
+        #[inline]
+        pub fn add_id(&mut self, id: u16) {
+            self.fbb_.push_slot::<u16>(ErrorResponse::VT_ID, id, 0);
+        }
+        #[inline]
+        pub fn add_other(&mut self, other: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(ErrorResponse::VT_OTHER, other);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+        ) -> ErrorResponseBuilder<'a, 'b> {
+            let start = _fbb.start_table();
+            ErrorResponseBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ErrorResponse<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ErrorResponse<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ErrorResponse");
+            ds.field("id", &self.id());
+            ds.field("other", &self.other());
+            ds.finish()
+        }
+    }
+    pub enum PacketOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Packet<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Packet<'a> {
+        type Inner = Packet<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Packet<'a> {
+        pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 4;
+        pub const VT_DATA: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Packet { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+            args: &'args PacketArgs,
+        ) -> flatbuffers::WIPOffset<Packet<'bldr>> {
+            let mut builder = PacketBuilder::new(_fbb);
+            if let Some(x) = args.data {
+                builder.add_data(x);
+            }
+            builder.add_data_type(args.data_type);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn data_type(&self) -> PacketData {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<PacketData>(Packet::VT_DATA_TYPE, Some(PacketData::NONE))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn data(&self) -> Option<flatbuffers::Table<'a>> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                        Packet::VT_DATA,
+                        None,
+                    )
+            }
+        }
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_put(&self) -> Option<TryPut<'a>> {
+            if self.data_type() == PacketData::TryPut {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryPut::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_put_success(&self) -> Option<PutSuccess<'a>> {
+            if self.data_type() == PacketData::PutSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { PutSuccess::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_get(&self) -> Option<TryGet<'a>> {
+            if self.data_type() == PacketData::TryGet {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryGet::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_get_success(&self) -> Option<GetSuccess<'a>> {
+            if self.data_type() == PacketData::GetSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { GetSuccess::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_delete(&self) -> Option<TryDelete<'a>> {
+            if self.data_type() == PacketData::TryDelete {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryDelete::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_delete_success(&self) -> Option<DeleteSuccess<'a>> {
+            if self.data_type() == PacketData::DeleteSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { DeleteSuccess::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_error_response(&self) -> Option<ErrorResponse<'a>> {
+            if self.data_type() == PacketData::ErrorResponse {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { ErrorResponse::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_handshake(&self) -> Option<TryHandshake<'a>> {
+            if self.data_type() == PacketData::TryHandshake {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryHandshake::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_handshake_success(&self) -> Option<HandshakeSuccess<'a>> {
+            if self.data_type() == PacketData::HandshakeSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { HandshakeSuccess::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_replace(&self) -> Option<TryReplace<'a>> {
+            if self.data_type() == PacketData::TryReplace {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryReplace::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_replace_success(&self) -> Option<ReplaceSuccess<'a>> {
+            if self.data_type() == PacketData::ReplaceSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { ReplaceSuccess::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_refresh(&self) -> Option<TryRefresh<'a>> {
+            if self.data_type() == PacketData::TryRefresh {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryRefresh::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_refresh_success(&self) -> Option<RefreshSuccess<'a>> {
+            if self.data_type() == PacketData::RefreshSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { RefreshSuccess::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_create_future(&self) -> Option<TryCreateFuture<'a>> {
+            if self.data_type() == PacketData::TryCreateFuture {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryCreateFuture::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_create_future_success(&self) -> Option<CreateFutureSuccess<'a>> {
+            if self.data_type() == PacketData::CreateFutureSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { CreateFutureSuccess::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_complete_future(&self) -> Option<TryCompleteFuture<'a>> {
+            if self.data_type() == PacketData::TryCompleteFuture {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryCompleteFuture::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_complete_future_success(&self) -> Option<CompleteFutureSuccess<'a>> {
+            if self.data_type() == PacketData::CompleteFutureSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { CompleteFutureSuccess::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_try_poll_future(&self) -> Option<TryPollFuture<'a>> {
+            if self.data_type() == PacketData::TryPollFuture {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryPollFuture::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_poll_future_success(&self) -> Option<PollFutureSuccess<'a>> {
+            if self.data_type() == PacketData::PollFutureSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { PollFutureSuccess::init_from_table(t) }
+                })
+            } else {
+                None
             }
-            builder.add_data_type(args.data_type);
-            builder.finish()
         }
 
         #[inline]
-        pub fn data_type(&self) -> PacketData {
-            // Safety:
-            // Created from valid Table for this object
-            // which contains a valid value in this slot
-            unsafe {
-                self._tab
-                    .get::<PacketData>(Packet::VT_DATA_TYPE, Some(PacketData::NONE))
-                    .unwrap()
+        #[allow(non_snake_case)]
+        pub fn data_as_try_fetch_future(&self) -> Option<TryFetchFuture<'a>> {
+            if self.data_type() == PacketData::TryFetchFuture {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TryFetchFuture::init_from_table(t) }
+                })
+            } else {
+                None
             }
         }
+
         #[inline]
-        pub fn data(&self) -> Option<flatbuffers::Table<'a>> {
-            // Safety:
-            // Created from valid Table for this object
-            // which contains a valid value in this slot
-            unsafe {
-                self._tab
-                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                        Packet::VT_DATA,
-                        None,
-                    )
+        #[allow(non_snake_case)]
+        pub fn data_as_fetch_future_success(&self) -> Option<FetchFutureSuccess<'a>> {
+            if self.data_type() == PacketData::FetchFutureSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { FetchFutureSuccess::init_from_table(t) }
+                })
+            } else {
+                None
             }
         }
+
         #[inline]
         #[allow(non_snake_case)]
-        pub fn data_as_try_put(&self) -> Option<TryPut<'a>> {
-            if self.data_type() == PacketData::TryPut {
+        pub fn data_as_try_push_item(&self) -> Option<TryPushItem<'a>> {
+            if self.data_type() == PacketData::TryPushItem {
                 self.data().map(|t| {
                     // Safety:
                     // Created from a valid Table for this object
                     // Which contains a valid union in this slot
-                    unsafe { TryPut::init_from_table(t) }
+                    unsafe { TryPushItem::init_from_table(t) }
                 })
             } else {
                 None
@@ -1117,13 +5558,13 @@ pub mod hex_flatbuffer {
 
         #[inline]
         #[allow(non_snake_case)]
-        pub fn data_as_put_success(&self) -> Option<PutSuccess<'a>> {
-            if self.data_type() == PacketData::PutSuccess {
+        pub fn data_as_push_item_success(&self) -> Option<PushItemSuccess<'a>> {
+            if self.data_type() == PacketData::PushItemSuccess {
                 self.data().map(|t| {
                     // Safety:
                     // Created from a valid Table for this object
                     // Which contains a valid union in this slot
-                    unsafe { PutSuccess::init_from_table(t) }
+                    unsafe { PushItemSuccess::init_from_table(t) }
                 })
             } else {
                 None
@@ -1132,13 +5573,13 @@ pub mod hex_flatbuffer {
 
         #[inline]
         #[allow(non_snake_case)]
-        pub fn data_as_try_get(&self) -> Option<TryGet<'a>> {
-            if self.data_type() == PacketData::TryGet {
+        pub fn data_as_try_pull_item(&self) -> Option<TryPullItem<'a>> {
+            if self.data_type() == PacketData::TryPullItem {
                 self.data().map(|t| {
                     // Safety:
                     // Created from a valid Table for this object
                     // Which contains a valid union in this slot
-                    unsafe { TryGet::init_from_table(t) }
+                    unsafe { TryPullItem::init_from_table(t) }
                 })
             } else {
                 None
@@ -1147,13 +5588,13 @@ pub mod hex_flatbuffer {
 
         #[inline]
         #[allow(non_snake_case)]
-        pub fn data_as_get_success(&self) -> Option<GetSuccess<'a>> {
-            if self.data_type() == PacketData::GetSuccess {
+        pub fn data_as_pull_item_success(&self) -> Option<PullItemSuccess<'a>> {
+            if self.data_type() == PacketData::PullItemSuccess {
                 self.data().map(|t| {
                     // Safety:
                     // Created from a valid Table for this object
                     // Which contains a valid union in this slot
-                    unsafe { GetSuccess::init_from_table(t) }
+                    unsafe { PullItemSuccess::init_from_table(t) }
                 })
             } else {
                 None
@@ -1162,13 +5603,13 @@ pub mod hex_flatbuffer {
 
         #[inline]
         #[allow(non_snake_case)]
-        pub fn data_as_try_delete(&self) -> Option<TryDelete<'a>> {
-            if self.data_type() == PacketData::TryDelete {
+        pub fn data_as_try_list(&self) -> Option<TryList<'a>> {
+            if self.data_type() == PacketData::TryList {
                 self.data().map(|t| {
                     // Safety:
                     // Created from a valid Table for this object
                     // Which contains a valid union in this slot
-                    unsafe { TryDelete::init_from_table(t) }
+                    unsafe { TryList::init_from_table(t) }
                 })
             } else {
                 None
@@ -1177,13 +5618,13 @@ pub mod hex_flatbuffer {
 
         #[inline]
         #[allow(non_snake_case)]
-        pub fn data_as_delete_success(&self) -> Option<DeleteSuccess<'a>> {
-            if self.data_type() == PacketData::DeleteSuccess {
+        pub fn data_as_list_success(&self) -> Option<ListSuccess<'a>> {
+            if self.data_type() == PacketData::ListSuccess {
                 self.data().map(|t| {
                     // Safety:
                     // Created from a valid Table for this object
                     // Which contains a valid union in this slot
-                    unsafe { DeleteSuccess::init_from_table(t) }
+                    unsafe { ListSuccess::init_from_table(t) }
                 })
             } else {
                 None
@@ -1192,13 +5633,28 @@ pub mod hex_flatbuffer {
 
         #[inline]
         #[allow(non_snake_case)]
-        pub fn data_as_error_response(&self) -> Option<ErrorResponse<'a>> {
-            if self.data_type() == PacketData::ErrorResponse {
+        pub fn data_as_try_exists(&self) -> Option<TryExists<'a>> {
+            if self.data_type() == PacketData::TryExists {
                 self.data().map(|t| {
                     // Safety:
                     // Created from a valid Table for this object
                     // Which contains a valid union in this slot
-                    unsafe { ErrorResponse::init_from_table(t) }
+                    unsafe { TryExists::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn data_as_exists_success(&self) -> Option<ExistsSuccess<'a>> {
+            if self.data_type() == PacketData::ExistsSuccess {
+                self.data().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { ExistsSuccess::init_from_table(t) }
                 })
             } else {
                 None
@@ -1214,52 +5670,41 @@ pub mod hex_flatbuffer {
         ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
             use self::flatbuffers::Verifiable;
             v.visit_table(pos)?
-                .visit_union::<PacketData, _>(
-                    "data_type",
-                    Self::VT_DATA_TYPE,
-                    "data",
-                    Self::VT_DATA,
-                    false,
-                    |key, v, pos| match key {
-                        PacketData::TryPut => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<TryPut>>(
-                                "PacketData::TryPut",
-                                pos,
-                            ),
-                        PacketData::PutSuccess => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<PutSuccess>>(
-                                "PacketData::PutSuccess",
-                                pos,
-                            ),
-                        PacketData::TryGet => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<TryGet>>(
-                                "PacketData::TryGet",
-                                pos,
-                            ),
-                        PacketData::GetSuccess => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<GetSuccess>>(
-                                "PacketData::GetSuccess",
-                                pos,
-                            ),
-                        PacketData::TryDelete => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<TryDelete>>(
-                                "PacketData::TryDelete",
-                                pos,
-                            ),
-                        PacketData::DeleteSuccess => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<DeleteSuccess>>(
-                                "PacketData::DeleteSuccess",
-                                pos,
-                            ),
-                        PacketData::ErrorResponse => v
-                            .verify_union_variant::<flatbuffers::ForwardsUOffset<ErrorResponse>>(
-                                "PacketData::ErrorResponse",
-                                pos,
-                            ),
-                        _ => Ok(()),
-                    },
-                )?
-                .finish();
+     .visit_union::<PacketData, _>("data_type", Self::VT_DATA_TYPE, "data", Self::VT_DATA, false, |key, v, pos| {
+        match key {
+          PacketData::TryPut => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryPut>>("PacketData::TryPut", pos),
+          PacketData::PutSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PutSuccess>>("PacketData::PutSuccess", pos),
+          PacketData::TryGet => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryGet>>("PacketData::TryGet", pos),
+          PacketData::GetSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GetSuccess>>("PacketData::GetSuccess", pos),
+          PacketData::TryDelete => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryDelete>>("PacketData::TryDelete", pos),
+          PacketData::DeleteSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DeleteSuccess>>("PacketData::DeleteSuccess", pos),
+          PacketData::ErrorResponse => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ErrorResponse>>("PacketData::ErrorResponse", pos),
+          PacketData::TryHandshake => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryHandshake>>("PacketData::TryHandshake", pos),
+          PacketData::HandshakeSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HandshakeSuccess>>("PacketData::HandshakeSuccess", pos),
+          PacketData::TryReplace => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryReplace>>("PacketData::TryReplace", pos),
+          PacketData::ReplaceSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReplaceSuccess>>("PacketData::ReplaceSuccess", pos),
+          PacketData::TryRefresh => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryRefresh>>("PacketData::TryRefresh", pos),
+          PacketData::RefreshSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RefreshSuccess>>("PacketData::RefreshSuccess", pos),
+          PacketData::TryCreateFuture => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryCreateFuture>>("PacketData::TryCreateFuture", pos),
+          PacketData::CreateFutureSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CreateFutureSuccess>>("PacketData::CreateFutureSuccess", pos),
+          PacketData::TryCompleteFuture => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryCompleteFuture>>("PacketData::TryCompleteFuture", pos),
+          PacketData::CompleteFutureSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CompleteFutureSuccess>>("PacketData::CompleteFutureSuccess", pos),
+          PacketData::TryPollFuture => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryPollFuture>>("PacketData::TryPollFuture", pos),
+          PacketData::PollFutureSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PollFutureSuccess>>("PacketData::PollFutureSuccess", pos),
+          PacketData::TryFetchFuture => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryFetchFuture>>("PacketData::TryFetchFuture", pos),
+          PacketData::FetchFutureSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchFutureSuccess>>("PacketData::FetchFutureSuccess", pos),
+          PacketData::TryPushItem => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryPushItem>>("PacketData::TryPushItem", pos),
+          PacketData::PushItemSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PushItemSuccess>>("PacketData::PushItemSuccess", pos),
+          PacketData::TryPullItem => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryPullItem>>("PacketData::TryPullItem", pos),
+          PacketData::PullItemSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PullItemSuccess>>("PacketData::PullItemSuccess", pos),
+          PacketData::TryList => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryList>>("PacketData::TryList", pos),
+          PacketData::ListSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ListSuccess>>("PacketData::ListSuccess", pos),
+          PacketData::TryExists => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TryExists>>("PacketData::TryExists", pos),
+          PacketData::ExistsSuccess => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ExistsSuccess>>("PacketData::ExistsSuccess", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
             Ok(())
         }
     }
@@ -1382,6 +5827,226 @@ pub mod hex_flatbuffer {
                         )
                     }
                 }
+                PacketData::TryHandshake => {
+                    if let Some(x) = self.data_as_try_handshake() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::HandshakeSuccess => {
+                    if let Some(x) = self.data_as_handshake_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryReplace => {
+                    if let Some(x) = self.data_as_try_replace() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::ReplaceSuccess => {
+                    if let Some(x) = self.data_as_replace_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryRefresh => {
+                    if let Some(x) = self.data_as_try_refresh() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::RefreshSuccess => {
+                    if let Some(x) = self.data_as_refresh_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryCreateFuture => {
+                    if let Some(x) = self.data_as_try_create_future() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::CreateFutureSuccess => {
+                    if let Some(x) = self.data_as_create_future_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryCompleteFuture => {
+                    if let Some(x) = self.data_as_try_complete_future() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::CompleteFutureSuccess => {
+                    if let Some(x) = self.data_as_complete_future_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryPollFuture => {
+                    if let Some(x) = self.data_as_try_poll_future() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::PollFutureSuccess => {
+                    if let Some(x) = self.data_as_poll_future_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryFetchFuture => {
+                    if let Some(x) = self.data_as_try_fetch_future() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::FetchFutureSuccess => {
+                    if let Some(x) = self.data_as_fetch_future_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryPushItem => {
+                    if let Some(x) = self.data_as_try_push_item() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::PushItemSuccess => {
+                    if let Some(x) = self.data_as_push_item_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryPullItem => {
+                    if let Some(x) = self.data_as_try_pull_item() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::PullItemSuccess => {
+                    if let Some(x) = self.data_as_pull_item_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryList => {
+                    if let Some(x) = self.data_as_try_list() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::ListSuccess => {
+                    if let Some(x) = self.data_as_list_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::TryExists => {
+                    if let Some(x) = self.data_as_try_exists() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                PacketData::ExistsSuccess => {
+                    if let Some(x) = self.data_as_exists_success() {
+                        ds.field("data", &x)
+                    } else {
+                        ds.field(
+                            "data",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
                 _ => {
                     let x: Option<()> = None;
                     ds.field("data", &x)
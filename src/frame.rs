@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+//! Framing for the `Messages` stream.
+//!
+//! Every `Messages` flatbuffer on the wire is preceded by its length as a
+//! little-endian u32, the same layout flatbuffers uses for size prefixed buffers.
+use std::{fmt, io};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// default for the largest frame either side will accept, 4MiB
+pub const DEFAULT_MAX_FRAME_SIZE: u32 = 4 * 1024 * 1024;
+
+#[derive(Debug)]
+pub enum FrameError {
+    /// the other side announced a frame bigger than we are willing to read
+    TooLarge(u32),
+    /// the connection closed part way through a frame
+    Truncated,
+    Io(io::Error),
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameError::TooLarge(len) => write!(f, "frame of {} bytes is too large", len),
+            FrameError::Truncated => write!(f, "connection closed in the middle of a frame"),
+            FrameError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for FrameError {}
+
+impl From<io::Error> for FrameError {
+    fn from(err: io::Error) -> Self {
+        if err.kind() == io::ErrorKind::UnexpectedEof {
+            FrameError::Truncated
+        } else {
+            FrameError::Io(err)
+        }
+    }
+}
+
+/// Reads the next frame, returning `None` if the connection was closed cleanly between frames.
+pub async fn read_frame<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    max_size: u32,
+) -> Result<Option<Vec<u8>>, FrameError> {
+    let mut len = [0u8; 4];
+    //only EOF before the first byte of the prefix is a clean close
+    let read = reader.read(&mut len).await?;
+    if read == 0 {
+        return Ok(None);
+    }
+    reader.read_exact(&mut len[read..]).await?;
+    let len = u32::from_le_bytes(len);
+    if len > max_size {
+        return Err(FrameError::TooLarge(len));
+    }
+    let mut buffer = vec![0u8; len as usize];
+    reader.read_exact(&mut buffer).await?;
+    Ok(Some(buffer))
+}
+
+/// Writes `data` as a single frame.
+pub async fn write_frame<W: AsyncWrite + Unpin>(writer: &mut W, data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "frame too large"))?;
+    writer.write_all(&len.to_le_bytes()).await?;
+    writer.write_all(data).await?;
+    writer.flush().await
+}
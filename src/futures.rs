@@ -0,0 +1,165 @@
+#![allow(dead_code)]
+//! Server side of the Futures from notes.md.
+//!
+//! The futures live in one top list of slots, each holding a UUID and (once
+//! completed) the resulting list of iotas. A handle is the index of a slot
+//! plus the UUID it had when the handle was made, so once a slot expires and
+//! gets reused for a new future every old handle to it stops working.
+use rand::Rng;
+use std::fmt;
+use time::OffsetDateTime;
+
+#[derive(Debug)]
+pub enum FutureError {
+    /// the slot is gone, expired or was reused for another future
+    Gone,
+    /// the password does not match the one handed out when the future was created
+    WrongPassword,
+    /// the future has not been completed yet
+    NotDone,
+    /// the future already has a result
+    AlreadyCompleted,
+    /// every slot is taken by a future that hasn't expired
+    Full,
+}
+
+impl fmt::Display for FutureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FutureError::Gone => write!(f, "the future no longer exists"),
+            FutureError::WrongPassword => write!(f, "wrong password for the future"),
+            FutureError::NotDone => write!(f, "the future has not exited yet"),
+            FutureError::AlreadyCompleted => write!(f, "the future has already exited"),
+            FutureError::Full => write!(f, "no free future slots"),
+        }
+    }
+}
+
+impl std::error::Error for FutureError {}
+
+/// One entry of the top list, a `Pair<Option<List<Iota>>, UUID>` plus what the server needs to manage it.
+#[derive(Debug, Clone)]
+struct FutureSlot {
+    uuid: [u8; 16],
+    password: Vec<u8>,
+    /// the NBT of every iota in the result, `None` until completed
+    result: Option<Vec<Vec<u8>>>,
+    deletion: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct Futures {
+    slots: Vec<Option<FutureSlot>>,
+    max_slots: usize,
+}
+
+impl Futures {
+    pub fn new(max_slots: usize) -> Self {
+        Futures {
+            slots: vec![],
+            max_slots,
+        }
+    }
+
+    /// Makes a new future, reusing the first free or expired slot, and returns its index and UUID.
+    pub fn create(
+        &mut self,
+        password: Vec<u8>,
+        deletion: OffsetDateTime,
+    ) -> Result<(u32, [u8; 16]), FutureError> {
+        let now = OffsetDateTime::now_utc();
+        let slot = FutureSlot {
+            uuid: random_uuid(),
+            password,
+            result: None,
+            deletion,
+        };
+        let uuid = slot.uuid;
+        let free = self
+            .slots
+            .iter()
+            .position(|s| s.as_ref().is_none_or(|s| s.deletion < now));
+        let index = match free {
+            Some(index) => {
+                self.slots[index] = Some(slot);
+                index
+            }
+            None if self.slots.len() < self.max_slots => {
+                self.slots.push(Some(slot));
+                self.slots.len() - 1
+            }
+            None => return Err(FutureError::Full),
+        };
+        Ok((index as u32, uuid))
+    }
+
+    /// Sets the result of the future, only allowed once and only with the password from [`Futures::create`].
+    pub fn complete(
+        &mut self,
+        index: u32,
+        uuid: &[u8],
+        password: &[u8],
+        result: Vec<Vec<u8>>,
+    ) -> Result<(), FutureError> {
+        let slot = self.slot_mut(index, uuid)?;
+        if slot.password != password {
+            return Err(FutureError::WrongPassword);
+        }
+        if slot.result.is_some() {
+            return Err(FutureError::AlreadyCompleted);
+        }
+        slot.result = Some(result);
+        Ok(())
+    }
+
+    /// Whether the future has exited (Tara's Purification).
+    pub fn poll(&self, index: u32, uuid: &[u8]) -> Result<bool, FutureError> {
+        Ok(self.slot(index, uuid)?.result.is_some())
+    }
+
+    /// The result of the future (Tara's Purification II).
+    pub fn fetch(&self, index: u32, uuid: &[u8]) -> Result<&[Vec<u8>], FutureError> {
+        self.slot(index, uuid)?
+            .result
+            .as_deref()
+            .ok_or(FutureError::NotDone)
+    }
+
+    /// Frees every slot whose deletion time has passed, returning how many were freed.
+    pub fn prune(&mut self) -> usize {
+        let now = OffsetDateTime::now_utc();
+        let mut pruned = 0;
+        for slot in &mut self.slots {
+            if slot.as_ref().is_some_and(|s| s.deletion < now) {
+                *slot = None;
+                pruned += 1;
+            }
+        }
+        pruned
+    }
+
+    fn slot(&self, index: u32, uuid: &[u8]) -> Result<&FutureSlot, FutureError> {
+        let now = OffsetDateTime::now_utc();
+        match self.slots.get(index as usize) {
+            Some(Some(slot)) if slot.uuid == uuid && slot.deletion >= now => Ok(slot),
+            _ => Err(FutureError::Gone),
+        }
+    }
+
+    fn slot_mut(&mut self, index: u32, uuid: &[u8]) -> Result<&mut FutureSlot, FutureError> {
+        let now = OffsetDateTime::now_utc();
+        match self.slots.get_mut(index as usize) {
+            Some(Some(slot)) if slot.uuid == uuid && slot.deletion >= now => Ok(slot),
+            _ => Err(FutureError::Gone),
+        }
+    }
+}
+
+/// a random (version 4) UUID
+fn random_uuid() -> [u8; 16] {
+    let mut uuid = [0u8; 16];
+    rand::thread_rng().fill(&mut uuid);
+    uuid[6] = (uuid[6] & 0x0f) | 0x40;
+    uuid[8] = (uuid[8] & 0x3f) | 0x80;
+    uuid
+}
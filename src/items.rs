@@ -0,0 +1,103 @@
+#![allow(dead_code)]
+//! The item id / item tag lists from notes.md, enforced by the overmind itself.
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+/// A `blacklist_*` list together with its `invert_*` flag.
+#[derive(Debug, Clone, Default)]
+pub struct IdList {
+    pub ids: HashSet<String>,
+    /// turns the blacklist into a whitelist
+    pub invert: bool,
+}
+
+impl IdList {
+    pub fn new(ids: impl IntoIterator<Item = String>, invert: bool) -> Self {
+        IdList {
+            ids: ids.into_iter().collect(),
+            invert,
+        }
+    }
+
+    /// whether `id` is caught by the list, being on a blacklist or missing from a whitelist
+    pub fn catches(&self, id: &str) -> bool {
+        self.ids.contains(id) != self.invert
+    }
+}
+
+/// Whether `tag` is a resource location (`namespace:path`), which every item tag is.
+///
+/// The backends keep tags comma separated, so anything else could split into other tags.
+pub fn is_resource_location(tag: &str) -> bool {
+    let Some((namespace, path)) = tag.split_once(':') else {
+        return false;
+    };
+    let valid = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || "_-.".contains(c);
+    !namespace.is_empty()
+        && !path.is_empty()
+        && namespace.chars().all(valid)
+        && path.chars().all(|c| valid(c) || c == '/')
+}
+
+/// Why an item was turned away, sent back as the details of the error.
+#[derive(Debug, Clone)]
+pub struct ItemRejection {
+    pub id: String,
+    /// the setting that rejected it
+    pub list: &'static str,
+    pub whitelist: bool,
+    /// the offending tag, for blacklisted tags
+    pub tag: Option<String>,
+}
+
+impl ItemRejection {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "item": self.id,
+            "list": self.list,
+            "whitelist": self.whitelist,
+            "tag": self.tag,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ItemPolicy {
+    /// `blacklist_import_items` / `invert_import_items`
+    pub items: IdList,
+    /// `blacklist_import_tags` / `invert_import_tags`, without the leading `#`
+    pub tags: IdList,
+}
+
+impl ItemPolicy {
+    /// Checks an item with id `id` and the item tags `tags` against both lists.
+    pub fn check(&self, id: &str, tags: &[String]) -> Result<(), ItemRejection> {
+        if self.items.catches(id) {
+            return Err(ItemRejection {
+                id: id.to_owned(),
+                list: "blacklist_import_items",
+                whitelist: self.items.invert,
+                tag: None,
+            });
+        }
+        let mut tags = tags.iter().map(|tag| tag.trim_start_matches('#'));
+        let tag = if self.tags.invert {
+            //a whitelist lets the item through if any one of its tags is listed
+            if tags.any(|tag| self.tags.ids.contains(tag)) {
+                return Ok(());
+            }
+            None
+        } else {
+            match tags.find(|tag| self.tags.ids.contains(*tag)) {
+                Some(tag) => Some(tag.to_owned()),
+                None => return Ok(()),
+            }
+        };
+        Err(ItemRejection {
+            id: id.to_owned(),
+            list: "blacklist_import_tags",
+            whitelist: self.tags.invert,
+            tag,
+        })
+    }
+}
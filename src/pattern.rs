@@ -0,0 +1,214 @@
+#![allow(dead_code)]
+//! Angle signatures, the patterns iotas are stored under.
+//!
+//! A signature is the list of turns taken while drawing a pattern, one of
+//! `w` (forward), `e` (right), `d` (sharp right), `s` (back), `a` (sharp left)
+//! and `q` (left) per stroke after the first. It doesn't depend on where or in
+//! which direction the pattern was started, but tracing the same shape from its
+//! other end gives the signature reversed with every turn mirrored, which
+//! [`canonicalize`] folds back together.
+//!
+//! Not every signature can be drawn, Hex Casting won't let a stroke go over an
+//! edge that is already part of the pattern (so `s` never shows up), which
+//! [`validate`] checks by walking the signature on the hex grid.
+//!
+//! Patterns can be put in a namespace (a server or team id) so unrelated servers
+//! drawing the same pattern don't collide, which is kept as a `namespace/` prefix
+//! on the key. Patterns without one (or in one of the global namespaces) share
+//! one space across every server.
+use std::{collections::HashSet, fmt};
+
+/// the longest key that fits the `Pattern` column
+pub const MAX_PATTERN_LEN: usize = 256;
+/// the longest a namespace may be
+pub const MAX_NAMESPACE_LEN: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// a character that isn't one of `qweasd`
+    InvalidChar { index: usize, found: char },
+    /// the empty signature
+    Empty,
+    /// more than `max` angles, [`MAX_PATTERN_LEN`] less the namespace prefix
+    TooLong { len: usize, max: usize },
+    /// the stroke after the angle at `index` goes over an edge that was already drawn
+    RetracesEdge { index: usize },
+    /// a namespace that is too long or has characters other than letters, digits and `_-.:`
+    InvalidNamespace { namespace: String },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::InvalidChar { index, found } => write!(
+                f,
+                "{:?} at {} is not an angle, only qweasd are",
+                found, index
+            ),
+            PatternError::Empty => write!(f, "the pattern is empty"),
+            PatternError::TooLong { len, max } => write!(
+                f,
+                "the pattern is {} angles long, at most {} are allowed",
+                len, max
+            ),
+            PatternError::RetracesEdge { index } => {
+                write!(f, "the stroke after angle {} retraces an edge", index)
+            }
+            PatternError::InvalidNamespace { namespace } => write!(
+                f,
+                "namespace {:?} must be at most {} letters, digits or _-.:",
+                namespace, MAX_NAMESPACE_LEN
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// the neighbours of a hex in axial coordinates, each one a clockwise turn from the last
+const DIRECTIONS: [(i32, i32); 6] = [(1, 0), (0, 1), (-1, 1), (-1, 0), (0, -1), (1, -1)];
+
+/// Checks that `sig` only has angles in it, without caring whether it can be drawn.
+pub fn validate_angles(sig: &str) -> Result<(), PatternError> {
+    if let Some((index, found)) = sig.char_indices().find(|(_, c)| !"qweasd".contains(*c)) {
+        return Err(PatternError::InvalidChar { index, found });
+    }
+    Ok(())
+}
+
+/// Checks that `sig` is an angle signature that can actually be drawn.
+pub fn validate(sig: &str) -> Result<(), PatternError> {
+    validate_angles(sig)?;
+    if sig.is_empty() {
+        return Err(PatternError::Empty);
+    }
+    if sig.len() > MAX_PATTERN_LEN {
+        return Err(PatternError::TooLong {
+            len: sig.len(),
+            max: MAX_PATTERN_LEN,
+        });
+    }
+    //rotation doesn't matter, so start at the origin heading in the first direction
+    let mut point = (0, 0);
+    let mut dir = 0;
+    let mut edges = HashSet::new();
+    //draws the next stroke, false if it was already drawn
+    let mut stroke = |dir: usize| {
+        let (dq, dr) = DIRECTIONS[dir];
+        let next = (point.0 + dq, point.1 + dr);
+        let edge = if point < next {
+            (point, next)
+        } else {
+            (next, point)
+        };
+        point = next;
+        edges.insert(edge)
+    };
+    stroke(dir);
+    for (index, angle) in sig.chars().enumerate() {
+        let turn = match angle {
+            'w' => 0,
+            'e' => 1,
+            'd' => 2,
+            's' => 3,
+            'a' => 4,
+            _ => 5, //q
+        };
+        dir = (dir + turn) % 6;
+        if !stroke(dir) {
+            return Err(PatternError::RetracesEdge { index });
+        }
+    }
+    Ok(())
+}
+
+/// the signature of the same pattern traced from its other end
+pub fn reverse(sig: &str) -> String {
+    sig.chars()
+        .rev()
+        .map(|c| match c {
+            'q' => 'e',
+            'e' => 'q',
+            'a' => 'd',
+            'd' => 'a',
+            other => other,
+        })
+        .collect()
+}
+
+/// The one signature both ways of tracing a pattern map to, the smaller of the two.
+pub fn canonicalize(sig: &str) -> String {
+    let reversed = reverse(sig);
+    if reversed.as_str() < sig {
+        reversed
+    } else {
+        sig.to_owned()
+    }
+}
+
+/// Checks that `namespace` can be used as a key prefix.
+pub fn validate_namespace(namespace: &str) -> Result<(), PatternError> {
+    let valid = |c: char| c.is_ascii_alphanumeric() || "_-.:".contains(c);
+    if namespace.len() > MAX_NAMESPACE_LEN || !namespace.chars().all(valid) {
+        return Err(PatternError::InvalidNamespace {
+            namespace: namespace.to_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// How the patterns in packets turn into storage keys.
+#[derive(Debug, Clone)]
+pub struct PatternPolicy {
+    /// store a pattern and the same pattern traced backwards under one key
+    pub canonical: bool,
+    /// namespaces that are the shared space every server sees, the same as no namespace
+    pub global_namespaces: HashSet<String>,
+}
+
+impl Default for PatternPolicy {
+    fn default() -> Self {
+        PatternPolicy {
+            canonical: false,
+            global_namespaces: HashSet::from(["global".to_owned()]),
+        }
+    }
+}
+
+impl PatternPolicy {
+    /// Validates `sig` and `namespace` and turns them into the key they are stored under.
+    pub fn storage_key(&self, sig: &str, namespace: Option<&str>) -> Result<String, PatternError> {
+        validate(sig)?;
+        let sig = if self.canonical {
+            canonicalize(sig)
+        } else {
+            sig.to_owned()
+        };
+        let prefix = self.key_prefix(namespace)?;
+        let max = MAX_PATTERN_LEN - prefix.len();
+        if sig.len() > max {
+            return Err(PatternError::TooLong {
+                len: sig.len(),
+                max,
+            });
+        }
+        Ok(prefix + &sig)
+    }
+
+    /// Validates `namespace` and turns it into what the keys of its patterns start with,
+    /// `namespace/` or nothing at all for the global ones.
+    ///
+    /// Namespaces are lower-cased, MySQL compares keys without caring about case so
+    /// `Team` and `team` have to be the same namespace everywhere.
+    pub fn key_prefix(&self, namespace: Option<&str>) -> Result<String, PatternError> {
+        let Some(namespace) = namespace.filter(|ns| !ns.is_empty()) else {
+            return Ok(String::new());
+        };
+        validate_namespace(namespace)?;
+        let namespace = namespace.to_ascii_lowercase();
+        if self.global_namespaces.contains(&namespace) {
+            return Ok(String::new());
+        }
+        Ok(format!("{}/", namespace))
+    }
+}
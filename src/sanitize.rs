@@ -0,0 +1,300 @@
+#![allow(dead_code)]
+//! What `sanatize_nbt` does with each iota type, loaded from a rule file at startup.
+//!
+//! The rule file is a JSON object from iota type id to its rules, each rule being
+//! either the name of an action or an object with an `action` and its settings:
+//!
+//! ```json
+//! {
+//!     "hexcasting:list": "recurse_list",
+//!     "hexcasting:entity": "garbage",
+//!     "hextweaks:dict": {"action": "recurse_dict", "keys": "k", "values": "v"},
+//!     "someaddon:thing": [
+//!         {"action": "recurse_list", "path": ["inner"]},
+//!         {"action": "drop_field", "path": ["owner"]}
+//!     ]
+//! }
+//! ```
+//!
+//! Paths are compound keys walked from `hexcasting:data`, an empty path is the data itself.
+//!
+//! Iota types missing from the file are handled according to [`UnknownTypes`], so in the
+//! strict modes the file doubles as the allowlist (`"pass"` lets a type through untouched).
+use serde_json::{json, Value};
+use std::{collections::HashMap, fmt, fs, io, path::Path, str::FromStr};
+
+use crate::items::IdList;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// leave the iota as it is
+    Pass,
+    /// replace the whole iota with garbage
+    Garbage,
+    /// sanatize every iota in the list at `path`
+    RecurseList { path: Vec<String> },
+    /// sanatize every iota in the `keys` and `values` lists of the compound at `path`
+    RecurseDict {
+        path: Vec<String>,
+        keys: String,
+        values: String,
+    },
+    /// remove the field at `path`
+    DropField { path: Vec<String> },
+}
+
+#[derive(Debug)]
+pub enum RulesError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    /// the rule for the given iota type is malformed
+    Invalid(String, String),
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::Io(err) => write!(f, "{}", err),
+            RulesError::Json(err) => write!(f, "{}", err),
+            RulesError::Invalid(iota_type, why) => {
+                write!(f, "invalid rule for {}: {}", iota_type, why)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+/// Why an iota was refused instead of sanitized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SanitizeError {
+    /// the serialized iota is bigger than `max_bytes`
+    Oversized { bytes: usize, max: usize },
+    /// the NBT nests deeper than `max_depth`
+    TooDeep { max: usize },
+    /// there are more than `max_iotas` iotas in it
+    TooManyIotas { max: usize },
+    /// an iota of a type without rules while unknown types are rejected
+    UnknownType { iota_type: String, path: String },
+    /// something where an iota should be that isn't a compound with a `hexcasting:type` string
+    NotAnIota { path: String },
+    /// an iota without `hexcasting:data`
+    MissingData { iota_type: String, path: String },
+    /// the rules for the iota type expect a list or compound at `path` that isn't there
+    WrongShape {
+        iota_type: String,
+        path: String,
+        expected: &'static str,
+    },
+}
+
+impl SanitizeError {
+    /// the details sent back with the error
+    pub fn to_json(&self) -> Value {
+        match self {
+            SanitizeError::Oversized { bytes, max } => {
+                json!({ "limit": "max_bytes", "max": max, "bytes": bytes })
+            }
+            SanitizeError::TooDeep { max } => json!({ "limit": "max_depth", "max": max }),
+            SanitizeError::TooManyIotas { max } => json!({ "limit": "max_iotas", "max": max }),
+            SanitizeError::UnknownType { iota_type, path } => {
+                json!({ "iota_type": iota_type, "path": path })
+            }
+            SanitizeError::NotAnIota { path } => {
+                json!({ "reason": self.to_string(), "path": path })
+            }
+            SanitizeError::MissingData { iota_type, path }
+            | SanitizeError::WrongShape {
+                iota_type, path, ..
+            } => {
+                json!({ "reason": self.to_string(), "iota_type": iota_type, "path": path })
+            }
+        }
+    }
+}
+
+impl fmt::Display for SanitizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SanitizeError::Oversized { bytes, max } => {
+                write!(f, "iota is {} bytes, more than the {} allowed", bytes, max)
+            }
+            SanitizeError::TooDeep { max } => write!(f, "iota nests deeper than {}", max),
+            SanitizeError::TooManyIotas { max } => {
+                write!(f, "iota holds more than {} iotas", max)
+            }
+            SanitizeError::UnknownType { iota_type, path } => {
+                write!(f, "unknown iota type {} at {}", iota_type, path)
+            }
+            SanitizeError::NotAnIota { path } => write!(f, "{} is not an iota", path),
+            SanitizeError::MissingData { iota_type, path } => {
+                write!(f, "{} at {} has no hexcasting:data", iota_type, path)
+            }
+            SanitizeError::WrongShape {
+                iota_type,
+                path,
+                expected,
+            } => write!(f, "{} should have a {} at {}", iota_type, expected, path),
+        }
+    }
+}
+
+impl std::error::Error for SanitizeError {}
+
+/// How big an iota may get before it is refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// how deep the NBT may nest, every compound and list being a level (vanilla allows 512)
+    pub max_depth: usize,
+    /// the largest the serialized NBT may be, in bytes
+    pub max_bytes: usize,
+    /// the most iotas in one put, counting every iota inside lists and dicts
+    pub max_iotas: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_depth: 512,
+            max_bytes: 1024 * 1024,
+            //the same cap hexcasting puts on a single iota
+            max_iotas: 1024,
+        }
+    }
+}
+
+/// What happens to iotas of a type without any rules.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownTypes {
+    /// let them through as they are
+    #[default]
+    Pass,
+    /// replace them with garbage
+    Garbage,
+    /// refuse the whole iota
+    Reject,
+}
+
+impl FromStr for UnknownTypes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pass" => Ok(UnknownTypes::Pass),
+            "garbage" => Ok(UnknownTypes::Garbage),
+            "reject" => Ok(UnknownTypes::Reject),
+            other => Err(format!("expected pass, garbage or reject, not {:?}", other)),
+        }
+    }
+}
+
+/// Everything `sanatize_nbt` needs to know about how to treat an iota.
+#[derive(Debug, Clone)]
+pub struct SanitizePolicy {
+    /// the rules for each known iota type
+    pub rules: HashMap<String, Vec<Action>>,
+    /// what happens to the types missing from `rules`
+    pub unknown_types: UnknownTypes,
+    /// which item stacks get their `tag` dropped
+    pub drop_nbt: IdList,
+    pub limits: Limits,
+}
+
+impl Default for SanitizePolicy {
+    /// the rules the server shipped with before they were configurable
+    fn default() -> Self {
+        let rules = [
+            ("hexcasting:null", Action::Pass),
+            ("hexcasting:garbage", Action::Pass),
+            ("hexcasting:double", Action::Pass),
+            ("hexcasting:boolean", Action::Pass),
+            ("hexcasting:vec3", Action::Pass),
+            ("hexcasting:pattern", Action::Pass),
+            ("hexcasting:list", Action::RecurseList { path: vec![] }),
+            ("hexcasting:entity", Action::Garbage),
+            (
+                "hextweaks:dict",
+                Action::RecurseDict {
+                    path: vec![],
+                    keys: "k".to_owned(),
+                    values: "v".to_owned(),
+                },
+            ),
+        ];
+        SanitizePolicy {
+            rules: rules
+                .into_iter()
+                .map(|(iota_type, action)| (iota_type.to_owned(), vec![action]))
+                .collect(),
+            unknown_types: UnknownTypes::default(),
+            drop_nbt: IdList::default(),
+            limits: Limits::default(),
+        }
+    }
+}
+
+impl SanitizePolicy {
+    /// Reads the rules from the JSON file at `path`.
+    pub fn load_rules(path: &Path) -> Result<HashMap<String, Vec<Action>>, RulesError> {
+        let text = fs::read_to_string(path).map_err(RulesError::Io)?;
+        let json = serde_json::from_str(&text).map_err(RulesError::Json)?;
+        parse_rules(&json)
+    }
+}
+
+fn parse_rules(json: &Value) -> Result<HashMap<String, Vec<Action>>, RulesError> {
+    let Value::Object(types) = json else {
+        return Err(RulesError::Invalid(
+            "the rule file".to_owned(),
+            "must be an object of iota type to rules".to_owned(),
+        ));
+    };
+    let mut rules = HashMap::new();
+    for (iota_type, rule) in types {
+        let actions = match rule {
+            Value::Array(actions) => actions.iter().map(parse_action).collect(),
+            action => parse_action(action).map(|action| vec![action]),
+        }
+        .map_err(|why| RulesError::Invalid(iota_type.clone(), why))?;
+        rules.insert(iota_type.clone(), actions);
+    }
+    Ok(rules)
+}
+
+fn parse_action(json: &Value) -> Result<Action, String> {
+    let (name, settings) = match json {
+        Value::String(name) => (name.as_str(), None),
+        Value::Object(settings) => match settings.get("action") {
+            Some(Value::String(name)) => (name.as_str(), Some(settings)),
+            _ => return Err("missing \"action\"".to_owned()),
+        },
+        _ => return Err("a rule must be an action name or an object".to_owned()),
+    };
+    let path = match settings.and_then(|s| s.get("path")) {
+        None => vec![],
+        Some(Value::Array(keys)) => keys
+            .iter()
+            .map(|key| key.as_str().map(str::to_owned))
+            .collect::<Option<_>>()
+            .ok_or("\"path\" must be a list of strings")?,
+        Some(_) => return Err("\"path\" must be a list of strings".to_owned()),
+    };
+    let field = |key: &str, default: &str| match settings.and_then(|s| s.get(key)) {
+        None => Ok(default.to_owned()),
+        Some(Value::String(value)) => Ok(value.clone()),
+        Some(_) => Err(format!("\"{}\" must be a string", key)),
+    };
+    match name {
+        "pass" => Ok(Action::Pass),
+        "garbage" => Ok(Action::Garbage),
+        "recurse_list" => Ok(Action::RecurseList { path }),
+        "recurse_dict" => Ok(Action::RecurseDict {
+            path,
+            keys: field("keys", "k")?,
+            values: field("values", "v")?,
+        }),
+        "drop_field" if path.is_empty() => Err("drop_field needs a \"path\"".to_owned()),
+        "drop_field" => Ok(Action::DropField { path }),
+        other => Err(format!("unknown action {:?}", other)),
+    }
+}
@@ -0,0 +1,425 @@
+use super::{IotaStat, Storage, StorageError, StoredIota, StoredItem};
+use async_trait::async_trait;
+use quartz_nbt::{
+    io::{read_nbt, write_nbt, Flavor},
+    NbtCompound, NbtList, NbtReprError, NbtTag,
+};
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+/// Keeps every iota (and pushed item) in a `HashMap` inside the server process.
+///
+/// When given a snapshot path the map is written out as NBT every
+/// `SNAPSHOT_INTERVAL` seconds (default 300) and once more on a clean shutdown,
+/// then read back in on startup, so it survives a restart without needing a database at all.
+/// A crash still loses whatever changed since the last snapshot.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    iotas: Arc<Mutex<HashMap<String, StoredIota>>>,
+    items: Arc<Mutex<HashMap<String, StoredItem>>>,
+    snapshot: Option<PathBuf>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `memory://` for a store that forgets everything on shutdown,
+    /// `memory:///path/to/snapshot.nbt` to also snapshot to that file.
+    pub async fn connect(url: &str) -> Result<Self, StorageError> {
+        let path = url
+            .trim_start_matches("memory:")
+            .trim_start_matches("//")
+            .trim();
+        if path.is_empty() {
+            return Ok(Self::new());
+        }
+        let storage = Self::with_snapshot(PathBuf::from(path))?;
+        Ok(storage)
+    }
+
+    fn with_snapshot(path: PathBuf) -> Result<Self, StorageError> {
+        let mut iotas = HashMap::new();
+        let mut items = HashMap::new();
+        if path.exists() {
+            let (snapshot_iotas, snapshot_items) = read_snapshot(&path)?;
+            for iota in snapshot_iotas {
+                iotas.insert(iota.pattern.clone(), iota);
+            }
+            for item in snapshot_items {
+                items.insert(item.pattern.clone(), item);
+            }
+            info!(
+                "loaded {} iotas and {} items from {}",
+                iotas.len(),
+                items.len(),
+                path.display()
+            );
+        }
+        let iotas = Arc::new(Mutex::new(iotas));
+        let items = Arc::new(Mutex::new(items));
+
+        //0 would rewrite the whole snapshot in a hot loop
+        let interval = env::var("SNAPSHOT_INTERVAL")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(300)
+            .max(1);
+        let storage = MemoryStorage {
+            iotas,
+            items,
+            snapshot: Some(path.clone()),
+        };
+        let task_iotas = storage.iotas.clone();
+        let task_items = storage.items.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+                snapshot(&path, &task_iotas, &task_items).await;
+            }
+        });
+        Ok(storage)
+    }
+}
+
+/// Writes everything in `iotas` and `items` to the snapshot at `path`, logging how it went.
+async fn snapshot(
+    path: &Path,
+    iotas: &Mutex<HashMap<String, StoredIota>>,
+    items: &Mutex<HashMap<String, StoredItem>>,
+) {
+    let snapshot_iotas = {
+        let iotas = iotas.lock().await;
+        iotas.values().cloned().collect::<Vec<_>>()
+    };
+    let snapshot_items = {
+        let items = items.lock().await;
+        items.values().cloned().collect::<Vec<_>>()
+    };
+    match write_snapshot(path, &snapshot_iotas, &snapshot_items) {
+        Ok(()) => info!(
+            "snapshotted {} iotas and {} items",
+            snapshot_iotas.len(),
+            snapshot_items.len()
+        ),
+        Err(err) => error!("failed to snapshot iotas: {}", err),
+    }
+}
+
+/// Reads back the iotas and items of a snapshot made by [`write_snapshot`].
+pub fn read_snapshot(path: &Path) -> Result<(Vec<StoredIota>, Vec<StoredItem>), StorageError> {
+    let bytes = fs::read(path).map_err(|e| StorageError::Backend(e.to_string()))?;
+    let (root, _) = read_nbt(&mut &bytes[..], Flavor::GzCompressed)
+        .map_err(|e| StorageError::Backend(e.to_string()))?;
+    let entries = root
+        .get::<_, &NbtList>("iotas")
+        .map_err(|e| StorageError::Backend(e.to_string()))?;
+    let mut iotas = vec![];
+    for entry in entries.iter() {
+        let NbtTag::Compound(entry) = entry else {
+            warn!("skipping snapshot entry that isn't a compound");
+            continue;
+        };
+        match read_snapshot_entry(entry) {
+            Ok(iota) => iotas.push(iota),
+            Err(err) => warn!("skipping broken snapshot entry: {}", err),
+        }
+    }
+    let mut items = vec![];
+    //snapshots from before items existed don't have the list at all
+    if let Ok(entries) = root.get::<_, &NbtList>("items") {
+        for entry in entries.iter() {
+            let NbtTag::Compound(entry) = entry else {
+                warn!("skipping snapshot item that isn't a compound");
+                continue;
+            };
+            match read_snapshot_item(entry) {
+                Ok(item) => items.push(item),
+                Err(err) => warn!("skipping broken snapshot item: {}", err),
+            }
+        }
+    }
+    Ok((iotas, items))
+}
+
+fn read_snapshot_entry(entry: &NbtCompound) -> Result<StoredIota, NbtReprError> {
+    Ok(StoredIota {
+        pattern: entry.get::<_, &str>("Pattern")?.to_owned(),
+        data: entry.get::<_, &[u8]>("Data")?.to_vec(),
+        password: entry.get::<_, &[u8]>("Password")?.to_vec(),
+        //an unreadable time just means it gets pruned on the next pass
+        deletion: OffsetDateTime::from_unix_timestamp(entry.get::<_, i64>("Deletion")?)
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH),
+        //snapshots from before the iota type was kept don't have these
+        iota_type: entry
+            .get::<_, &str>("IotaType")
+            .unwrap_or_default()
+            .to_owned(),
+        sanitized: entry.get::<_, bool>("Sanitized").unwrap_or_default(),
+    })
+}
+
+fn same_item(a: &StoredItem, b: &StoredItem) -> bool {
+    a.item == b.item && a.count == b.count && a.tags == b.tags
+}
+
+fn read_snapshot_item(entry: &NbtCompound) -> Result<StoredItem, NbtReprError> {
+    Ok(StoredItem {
+        pattern: entry.get::<_, &str>("Pattern")?.to_owned(),
+        item: entry.get::<_, &[u8]>("Item")?.to_vec(),
+        count: entry.get::<_, i64>("Count")? as u64,
+        tags: entry
+            .get::<_, &NbtList>("Tags")?
+            .iter()
+            .filter_map(|tag| match tag {
+                NbtTag::String(tag) => Some(tag.clone()),
+                _ => None,
+            })
+            .collect(),
+        deletion: OffsetDateTime::from_unix_timestamp(entry.get::<_, i64>("Deletion")?)
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH),
+    })
+}
+
+/// Writes every iota and item to `path` as gzipped NBT.
+pub fn write_snapshot(
+    path: &Path,
+    iotas: &[StoredIota],
+    items: &[StoredItem],
+) -> Result<(), StorageError> {
+    let mut entries = NbtList::new();
+    for iota in iotas {
+        let mut entry = NbtCompound::new();
+        entry.insert("Pattern", iota.pattern.as_str());
+        entry.insert("Data", iota.data.clone());
+        entry.insert("Password", iota.password.clone());
+        entry.insert("Deletion", iota.deletion.unix_timestamp());
+        entry.insert("IotaType", iota.iota_type.as_str());
+        entry.insert("Sanitized", iota.sanitized);
+        entries.push(entry);
+    }
+    let mut item_entries = NbtList::new();
+    for item in items {
+        let mut entry = NbtCompound::new();
+        entry.insert("Pattern", item.pattern.as_str());
+        entry.insert("Item", item.item.clone());
+        entry.insert("Count", item.count as i64);
+        entry.insert(
+            "Tags",
+            item.tags
+                .iter()
+                .map(|tag| NbtTag::String(tag.clone()))
+                .collect::<NbtList>(),
+        );
+        entry.insert("Deletion", item.deletion.unix_timestamp());
+        item_entries.push(entry);
+    }
+    let mut root = NbtCompound::new();
+    root.insert("iotas", entries);
+    root.insert("items", item_entries);
+
+    let mut bytes = vec![];
+    write_nbt(&mut bytes, None, &root, Flavor::GzCompressed)
+        .map_err(|e| StorageError::Backend(e.to_string()))?;
+    //write next to the snapshot then swap it in, so a crash mid-write can't eat the old one
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, bytes).map_err(|e| StorageError::Backend(e.to_string()))?;
+    fs::rename(&tmp, path).map_err(|e| StorageError::Backend(e.to_string()))
+}
+
+#[async_trait]
+impl Storage for MemoryStorage {
+    async fn put(&self, iota: StoredIota) -> Result<(), StorageError> {
+        let mut iotas = self.iotas.lock().await;
+        let now = OffsetDateTime::now_utc();
+        //an expired iota that hasn't been pruned yet doesn't hold on to its pattern
+        if iotas
+            .get(&iota.pattern)
+            .is_some_and(|old| old.deletion >= now)
+        {
+            return Err(StorageError::AlreadyExists(iota.pattern));
+        }
+        iotas.insert(iota.pattern.clone(), iota);
+        Ok(())
+    }
+
+    async fn get(&self, pattern: &str) -> Result<Vec<u8>, StorageError> {
+        let iotas = self.iotas.lock().await;
+        let now = OffsetDateTime::now_utc();
+        iotas
+            .get(pattern)
+            .filter(|iota| iota.deletion >= now)
+            .map(|iota| iota.data.clone())
+            .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))
+    }
+
+    async fn stat(&self, pattern: &str) -> Result<IotaStat, StorageError> {
+        let iotas = self.iotas.lock().await;
+        let now = OffsetDateTime::now_utc();
+        iotas
+            .get(pattern)
+            .filter(|iota| iota.deletion >= now)
+            .map(|iota| IotaStat {
+                size: iota.data.len() as u64,
+                iota_type: iota.iota_type.clone(),
+                sanitized: iota.sanitized,
+                deletion: iota.deletion,
+            })
+            .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))
+    }
+
+    async fn replace(
+        &self,
+        pattern: &str,
+        password: &[u8],
+        data: Vec<u8>,
+        iota_type: &str,
+        sanitized: bool,
+        deletion: Option<OffsetDateTime>,
+    ) -> Result<(), StorageError> {
+        let mut iotas = self.iotas.lock().await;
+        let now = OffsetDateTime::now_utc();
+        match iotas.get_mut(pattern) {
+            //expired but not pruned yet, it can't be brought back
+            Some(iota) if iota.deletion < now => Err(StorageError::NotFound(pattern.to_owned())),
+            Some(iota) if iota.password == password => {
+                iota.data = data;
+                iota.iota_type = iota_type.to_owned();
+                iota.sanitized = sanitized;
+                if let Some(deletion) = deletion {
+                    iota.deletion = deletion;
+                }
+                Ok(())
+            }
+            Some(_) => Err(StorageError::WrongPassword(pattern.to_owned())),
+            None => Err(StorageError::NotFound(pattern.to_owned())),
+        }
+    }
+
+    async fn refresh(
+        &self,
+        pattern: &str,
+        password: &[u8],
+        deletion: OffsetDateTime,
+    ) -> Result<(), StorageError> {
+        let mut iotas = self.iotas.lock().await;
+        let now = OffsetDateTime::now_utc();
+        match iotas.get_mut(pattern) {
+            //expired but not pruned yet, it can't be brought back
+            Some(iota) if iota.deletion < now => Err(StorageError::NotFound(pattern.to_owned())),
+            Some(iota) if iota.password == password => {
+                iota.deletion = deletion;
+                Ok(())
+            }
+            Some(_) => Err(StorageError::WrongPassword(pattern.to_owned())),
+            None => Err(StorageError::NotFound(pattern.to_owned())),
+        }
+    }
+
+    async fn delete(&self, pattern: &str, password: &[u8]) -> Result<(), StorageError> {
+        let mut iotas = self.iotas.lock().await;
+        let now = OffsetDateTime::now_utc();
+        match iotas.get(pattern) {
+            //expired but not pruned yet, the same as the SQL backends
+            Some(iota) if iota.deletion < now => Err(StorageError::NotFound(pattern.to_owned())),
+            Some(iota) if iota.password == password => {
+                iotas.remove(pattern);
+                Ok(())
+            }
+            Some(_) => Err(StorageError::WrongPassword(pattern.to_owned())),
+            None => Err(StorageError::NotFound(pattern.to_owned())),
+        }
+    }
+
+    async fn push_item(&self, item: StoredItem) -> Result<(), StorageError> {
+        let mut items = self.items.lock().await;
+        if items.contains_key(&item.pattern) {
+            return Err(StorageError::AlreadyExists(item.pattern));
+        }
+        items.insert(item.pattern.clone(), item);
+        Ok(())
+    }
+
+    async fn peek_item(&self, pattern: &str) -> Result<StoredItem, StorageError> {
+        let items = self.items.lock().await;
+        items
+            .get(pattern)
+            .cloned()
+            .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))
+    }
+
+    async fn pull_item(&self, seen: &StoredItem) -> Result<(), StorageError> {
+        let mut items = self.items.lock().await;
+        match items.get(&seen.pattern) {
+            Some(item) if same_item(item, seen) => {
+                items.remove(&seen.pattern);
+                Ok(())
+            }
+            _ => Err(StorageError::NotFound(seen.pattern.clone())),
+        }
+    }
+
+    async fn prune(&self) -> Result<u64, StorageError> {
+        let now = OffsetDateTime::now_utc();
+        let pruned_iotas = {
+            let mut iotas = self.iotas.lock().await;
+            let before = iotas.len();
+            iotas.retain(|_, iota| iota.deletion >= now);
+            before - iotas.len()
+        };
+        let pruned_items = {
+            let mut items = self.items.lock().await;
+            let before = items.len();
+            items.retain(|_, item| item.deletion >= now);
+            before - items.len()
+        };
+        Ok((pruned_iotas + pruned_items) as u64)
+    }
+
+    async fn list(&self) -> Result<Vec<StoredIota>, StorageError> {
+        let iotas = self.iotas.lock().await;
+        let mut list = iotas.values().cloned().collect::<Vec<_>>();
+        list.sort_by_key(|iota| iota.deletion);
+        Ok(list)
+    }
+
+    async fn list_patterns(
+        &self,
+        prefix: &str,
+        after: &str,
+        limit: u32,
+    ) -> Result<Vec<String>, StorageError> {
+        let iotas = self.iotas.lock().await;
+        let now = OffsetDateTime::now_utc();
+        let mut patterns = iotas
+            .values()
+            .filter(|iota| iota.deletion >= now)
+            .map(|iota| &iota.pattern)
+            .filter(|pattern| pattern.as_str() > after)
+            .filter(|pattern| {
+                pattern
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| !rest.contains('/'))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        patterns.sort();
+        patterns.truncate(limit as usize);
+        Ok(patterns)
+    }
+
+    async fn close(&self) {
+        if let Some(path) = &self.snapshot {
+            snapshot(path, &self.iotas, &self.items).await;
+        }
+    }
+}
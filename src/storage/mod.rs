@@ -0,0 +1,256 @@
+#![allow(dead_code)]
+//! Backends that hold the iotas stored in the overmind.
+//!
+//! `handle_conn` only ever talks to a [`Storage`], so the server does not care
+//! where the `HexDataStorage` (and `HexItemStorage`) rows actually live.
+//!
+//! The SQL backends use the unchecked `query` functions rather than `query!`,
+//! the macros can only check against the one database in `DATABASE_URL` at
+//! build time, which doesn't work once there is more than one kind of database.
+use async_trait::async_trait;
+use sqlx::{
+    database::HasArguments, query, ColumnIndex, Database, Decode, Encode, Executor, IntoArguments,
+    Pool, Row, Type,
+};
+use std::fmt;
+use time::OffsetDateTime;
+
+pub mod memory;
+mod mysql;
+mod sqlite;
+
+pub use memory::MemoryStorage;
+pub use mysql::MySqlStorage;
+pub use sqlite::SqliteStorage;
+
+/// A single `HexDataStorage` row.
+#[derive(Debug, Clone)]
+pub struct StoredIota {
+    pub pattern: String,
+    pub data: Vec<u8>,
+    pub password: Vec<u8>,
+    pub deletion: OffsetDateTime,
+    /// `hexcasting:type` of the top level iota, empty for iotas stored before it was kept
+    pub iota_type: String,
+    /// whether an entity in the iota was replaced with garbage when it was put
+    pub sanitized: bool,
+}
+
+/// What [`Storage::stat`] knows about a stored iota, without its NBT.
+#[derive(Debug, Clone)]
+pub struct IotaStat {
+    /// length of the stored NBT in bytes
+    pub size: u64,
+    pub iota_type: String,
+    pub sanitized: bool,
+    pub deletion: OffsetDateTime,
+}
+
+/// A single `HexItemStorage` row, items pushed through the overmind waiting to be pulled.
+#[derive(Debug, Clone)]
+pub struct StoredItem {
+    pub pattern: String,
+    /// the NBT of the item stack, its count is kept in `count` instead
+    pub item: Vec<u8>,
+    pub count: u64,
+    /// the item tags the pushing server says the item has, used for `blacklist_import_tags`
+    pub tags: Vec<String>,
+    pub deletion: OffsetDateTime,
+}
+
+#[derive(Debug)]
+pub enum StorageError {
+    /// nothing is stored at that pattern
+    NotFound(String),
+    /// something is already stored at that pattern
+    AlreadyExists(String),
+    /// the password does not match the one stored with that pattern
+    WrongPassword(String),
+    /// the backend itself failed (connection dropped, bad query, io error, ...)
+    Backend(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::NotFound(pattern) => write!(f, "nothing stored at {}", pattern),
+            StorageError::AlreadyExists(pattern) => {
+                write!(f, "pattern {} is already in use", pattern)
+            }
+            StorageError::WrongPassword(pattern) => {
+                write!(f, "wrong password for pattern {}", pattern)
+            }
+            StorageError::Backend(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+impl From<sqlx::Error> for StorageError {
+    fn from(err: sqlx::Error) -> Self {
+        StorageError::Backend(err.to_string())
+    }
+}
+
+/// Where the iotas and items live.
+///
+/// Iotas whose deletion time has passed are gone as far as everything but [`Storage::prune`]
+/// and [`Storage::list`] is concerned, even before they are pruned.
+#[async_trait]
+pub trait Storage: Send + Sync + fmt::Debug {
+    /// Stores a new iota under `iota.pattern`, failing with [`StorageError::AlreadyExists`] if it is taken.
+    /// An expired iota there is overwritten.
+    async fn put(&self, iota: StoredIota) -> Result<(), StorageError>;
+    /// Fetches the NBT stored under `pattern`, failing with [`StorageError::NotFound`] if there is none.
+    async fn get(&self, pattern: &str) -> Result<Vec<u8>, StorageError>;
+    /// Fetches everything but the NBT of the iota under `pattern`, failing with [`StorageError::NotFound`] if there is none.
+    async fn stat(&self, pattern: &str) -> Result<IotaStat, StorageError>;
+    /// Overwrites the NBT (and its `iota_type` and `sanitized`) under `pattern` if `password` matches,
+    /// keeping the old deletion time when `deletion` is `None`.
+    ///
+    /// Fails with [`StorageError::NotFound`] or [`StorageError::WrongPassword`] when nothing was replaced.
+    async fn replace(
+        &self,
+        pattern: &str,
+        password: &[u8],
+        data: Vec<u8>,
+        iota_type: &str,
+        sanitized: bool,
+        deletion: Option<OffsetDateTime>,
+    ) -> Result<(), StorageError>;
+    /// Moves the deletion time of the iota under `pattern` to `deletion` if `password` matches.
+    ///
+    /// Fails with [`StorageError::NotFound`] or [`StorageError::WrongPassword`] when nothing was refreshed.
+    async fn refresh(
+        &self,
+        pattern: &str,
+        password: &[u8],
+        deletion: OffsetDateTime,
+    ) -> Result<(), StorageError>;
+    /// Deletes the iota under `pattern` if `password` matches.
+    ///
+    /// Fails with [`StorageError::NotFound`] or [`StorageError::WrongPassword`] when nothing was deleted.
+    async fn delete(&self, pattern: &str, password: &[u8]) -> Result<(), StorageError>;
+    /// Stores items under `item.pattern`, failing with [`StorageError::AlreadyExists`] if it is taken.
+    async fn push_item(&self, item: StoredItem) -> Result<(), StorageError>;
+    /// Fetches the items under `pattern` without removing them, failing with [`StorageError::NotFound`] if there are none.
+    async fn peek_item(&self, pattern: &str) -> Result<StoredItem, StorageError>;
+    /// Removes `seen` (from [`Storage::peek_item`]) if it is still exactly what is stored,
+    /// failing with [`StorageError::NotFound`] otherwise.
+    ///
+    /// This is atomic, when two servers pull at once only one of them gets the items.
+    async fn pull_item(&self, seen: &StoredItem) -> Result<(), StorageError>;
+    /// Deletes every iota and item whose deletion time has passed, returning how many rows went away.
+    async fn prune(&self) -> Result<u64, StorageError>;
+    /// Every stored iota, ordered by deletion time.
+    async fn list(&self) -> Result<Vec<StoredIota>, StorageError>;
+    /// Up to `limit` stored patterns that start with `prefix` and sort after `after`, in order.
+    ///
+    /// Patterns with a `/` after `prefix` are in a namespace below it and left out.
+    /// The SQL backends do this as a range scan on the `Pattern` primary key.
+    async fn list_patterns(
+        &self,
+        prefix: &str,
+        after: &str,
+        limit: u32,
+    ) -> Result<Vec<String>, StorageError>;
+    /// Called once when the server shuts down cleanly, to write out anything that is only in memory.
+    async fn close(&self) {}
+}
+
+/// Works out whether a password checked query matched nothing because of the pattern or the password.
+///
+/// `now` is the backend's SQL for the current time the way it keeps `Deletion`,
+/// expired rows that haven't been pruned yet count as gone.
+async fn not_matched<DB>(pool: &Pool<DB>, pattern: &str, now: &str) -> StorageError
+where
+    DB: Database,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+{
+    let sql = format!(
+        "SELECT 1 FROM HexDataStorage WHERE Pattern = ? AND Deletion >= {};",
+        now
+    );
+    let exists = query::<DB>(&sql).bind(pattern).fetch_optional(pool).await;
+    match exists {
+        Ok(Some(_)) => StorageError::WrongPassword(pattern.to_owned()),
+        Ok(None) => StorageError::NotFound(pattern.to_owned()),
+        Err(e) => e.into(),
+    }
+}
+
+/// [`Storage::list_patterns`] for the SQL backends, `now` being the same as for [`not_matched`].
+async fn list_patterns<DB>(
+    pool: &Pool<DB>,
+    prefix: &str,
+    after: &str,
+    limit: u32,
+    now: &str,
+) -> Result<Vec<String>, StorageError>
+where
+    DB: Database,
+    for<'c> &'c Pool<DB>: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Decode<'q, DB> + Type<DB>,
+    for<'q> u32: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: ColumnIndex<DB::Row>,
+{
+    let end = prefix_end(prefix);
+    let mut sql = format!(
+        "SELECT Pattern FROM HexDataStorage WHERE Deletion >= {} AND Pattern >= ? AND Pattern > ?",
+        now
+    );
+    if end.is_some() {
+        sql.push_str(" AND Pattern < ?");
+    }
+    if !prefix.contains('/') {
+        sql.push_str(" AND Pattern NOT LIKE '%/%'");
+    }
+    sql.push_str(" ORDER BY Pattern LIMIT ?;");
+    let mut select = query::<DB>(&sql).bind(prefix).bind(after);
+    if let Some(end) = end {
+        select = select.bind(end);
+    }
+    let rows = select.bind(limit).fetch_all(pool).await?;
+    rows.iter()
+        .map(|row| Ok(row.try_get::<String, _>("Pattern")?))
+        .collect()
+}
+
+/// the comma separated `Tags` column of `HexItemStorage` as a list
+fn split_tags(tags: String) -> Vec<String> {
+    tags.split(',')
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// The smallest string that sorts after every string starting with `prefix`, `None` if there is none.
+fn prefix_end(prefix: &str) -> Option<String> {
+    let mut end = prefix.to_owned();
+    while let Some(last) = end.pop() {
+        if let Some(next) = char::from_u32(last as u32 + 1) {
+            end.push(next);
+            return Some(end);
+        }
+    }
+    None
+}
+
+/// Connects to the backend described by `url` (the `DATABASE_URL`) and makes sure it is set up.
+///
+/// `sqlite://` urls get a [`SqliteStorage`], `memory://` urls a [`MemoryStorage`],
+/// anything else is handed to MySQL.
+pub async fn connect(url: &str) -> Result<Box<dyn Storage>, StorageError> {
+    if url.starts_with("memory:") {
+        Ok(Box::new(MemoryStorage::connect(url).await?))
+    } else if url.starts_with("sqlite:") {
+        Ok(Box::new(SqliteStorage::connect(url).await?))
+    } else {
+        Ok(Box::new(MySqlStorage::connect(url).await?))
+    }
+}
@@ -0,0 +1,286 @@
+use super::{
+    list_patterns, not_matched, split_tags, IotaStat, Storage, StorageError, StoredIota, StoredItem,
+};
+use async_trait::async_trait;
+use sqlx::{
+    mysql::{MySqlDatabaseError, MySqlPool},
+    query, Row,
+};
+use time::OffsetDateTime;
+
+/// `ER_DUP_ENTRY`, what MySQL says when the `Pattern` primary key is already taken
+const ER_DUP_ENTRY: u16 = 1062;
+
+/// The original backend, a `HexDataStorage` table in MySQL/MariaDB.
+#[derive(Debug)]
+pub struct MySqlStorage {
+    pool: MySqlPool,
+}
+
+impl MySqlStorage {
+    pub async fn connect(url: &str) -> Result<Self, StorageError> {
+        let pool = MySqlPool::connect(url).await?;
+        query(
+            "
+        CREATE TABLE IF NOT EXISTS `HexDataStorage` (
+            Pattern VARCHAR(256) COMMENT 'the pattern to lookup db info' NOT NULL,
+            Data MEDIUMBLOB COMMENT 'the NBT data of the object' NOT NULL,
+            Password TINYBLOB COMMENT 'the key to delete this data' NOT NULL,
+            Deletion TIMESTAMP COMMENT 'The time when this data will be deleted' NOT NULL,
+            IotaType VARCHAR(256) COMMENT 'hexcasting:type of the top level iota' NOT NULL DEFAULT '',
+            Sanitized BOOLEAN COMMENT 'whether an entity in it was replaced with garbage' NOT NULL DEFAULT FALSE,
+            PRIMARY KEY (Pattern)
+        );",
+        )
+        .execute(&pool)
+        .await?;
+        //tables made before the iota type was kept don't have the columns yet
+        if query("SELECT IotaType FROM `HexDataStorage` LIMIT 0;")
+            .fetch_optional(&pool)
+            .await
+            .is_err()
+        {
+            query(
+                "
+        ALTER TABLE `HexDataStorage`
+            ADD COLUMN IotaType VARCHAR(256) COMMENT 'hexcasting:type of the top level iota' NOT NULL DEFAULT '',
+            ADD COLUMN Sanitized BOOLEAN COMMENT 'whether an entity in it was replaced with garbage' NOT NULL DEFAULT FALSE;",
+            )
+            .execute(&pool)
+            .await?;
+        }
+        query(
+            "
+        CREATE TABLE IF NOT EXISTS `HexItemStorage` (
+            Pattern VARCHAR(256) COMMENT 'the pattern the items were pushed to' NOT NULL,
+            Item MEDIUMBLOB COMMENT 'the NBT of the item stack' NOT NULL,
+            Count BIGINT UNSIGNED COMMENT 'how many of the item there are' NOT NULL,
+            Tags TEXT COMMENT 'comma separated item tags of the item' NOT NULL,
+            Deletion TIMESTAMP COMMENT 'The time when these items will be deleted' NOT NULL,
+            PRIMARY KEY (Pattern)
+        );",
+        )
+        .execute(&pool)
+        .await?;
+        Ok(MySqlStorage { pool })
+    }
+}
+
+fn is_duplicate(err: &sqlx::Error) -> bool {
+    err.as_database_error()
+        .and_then(|e| e.try_downcast_ref::<MySqlDatabaseError>())
+        .is_some_and(|e| e.number() == ER_DUP_ENTRY)
+}
+
+/// the current time, what `Deletion` is compared against
+const NOW: &str = "NOW()";
+
+#[async_trait]
+impl Storage for MySqlStorage {
+    async fn put(&self, iota: StoredIota) -> Result<(), StorageError> {
+        let pattern = iota.pattern.clone();
+        //an expired iota that hasn't been pruned yet doesn't hold on to its pattern
+        query("DELETE FROM HexDataStorage WHERE Pattern = ? AND Deletion < NOW();")
+            .bind(&pattern)
+            .execute(&self.pool)
+            .await?;
+        query("INSERT INTO HexDataStorage (Pattern, Data, Password, Deletion, IotaType, Sanitized) VALUES (?,?,?,?,?,?)")
+            .bind(iota.pattern)
+            .bind(iota.data)
+            .bind(iota.password)
+            .bind(iota.deletion)
+            .bind(iota.iota_type)
+            .bind(iota.sanitized)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                if is_duplicate(&e) {
+                    StorageError::AlreadyExists(pattern)
+                } else {
+                    e.into()
+                }
+            })?;
+        Ok(())
+    }
+
+    async fn get(&self, pattern: &str) -> Result<Vec<u8>, StorageError> {
+        let row = query("SELECT Data FROM HexDataStorage WHERE Pattern = ? AND Deletion >= NOW();")
+            .bind(pattern)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))?;
+        Ok(row.try_get("Data")?)
+    }
+
+    async fn stat(&self, pattern: &str) -> Result<IotaStat, StorageError> {
+        let row = query(
+            "SELECT LENGTH(Data) AS Size, IotaType, Sanitized, Deletion FROM HexDataStorage WHERE Pattern = ? AND Deletion >= NOW();",
+        )
+        .bind(pattern)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))?;
+        Ok(IotaStat {
+            size: row.try_get::<i64, _>("Size")? as u64,
+            iota_type: row.try_get("IotaType")?,
+            sanitized: row.try_get("Sanitized")?,
+            deletion: row.try_get("Deletion")?,
+        })
+    }
+
+    async fn replace(
+        &self,
+        pattern: &str,
+        password: &[u8],
+        data: Vec<u8>,
+        iota_type: &str,
+        sanitized: bool,
+        deletion: Option<OffsetDateTime>,
+    ) -> Result<(), StorageError> {
+        let update = match deletion {
+            Some(deletion) => query(
+                "UPDATE HexDataStorage SET Data = ?, IotaType = ?, Sanitized = ?, Deletion = ? WHERE Pattern = ? AND Password = ? AND Deletion >= NOW();",
+            )
+            .bind(data)
+            .bind(iota_type)
+            .bind(sanitized)
+            .bind(deletion),
+            None => query("UPDATE HexDataStorage SET Data = ?, IotaType = ?, Sanitized = ? WHERE Pattern = ? AND Password = ? AND Deletion >= NOW();")
+                .bind(data)
+                .bind(iota_type)
+                .bind(sanitized),
+        };
+        let res = update
+            .bind(pattern)
+            .bind(password)
+            .execute(&self.pool)
+            .await?;
+        if res.rows_affected() > 0 {
+            return Ok(());
+        }
+        Err(not_matched(&self.pool, pattern, NOW).await)
+    }
+
+    async fn refresh(
+        &self,
+        pattern: &str,
+        password: &[u8],
+        deletion: OffsetDateTime,
+    ) -> Result<(), StorageError> {
+        let res =
+            query("UPDATE HexDataStorage SET Deletion = ? WHERE Pattern = ? AND Password = ? AND Deletion >= NOW();")
+                .bind(deletion)
+                .bind(pattern)
+                .bind(password)
+                .execute(&self.pool)
+                .await?;
+        if res.rows_affected() > 0 {
+            return Ok(());
+        }
+        Err(not_matched(&self.pool, pattern, NOW).await)
+    }
+
+    async fn delete(&self, pattern: &str, password: &[u8]) -> Result<(), StorageError> {
+        let res = query("DELETE FROM HexDataStorage WHERE Pattern = ? AND Password = ?;")
+            .bind(pattern)
+            .bind(password)
+            .execute(&self.pool)
+            .await?;
+        if res.rows_affected() > 0 {
+            return Ok(());
+        }
+        Err(not_matched(&self.pool, pattern, NOW).await)
+    }
+
+    async fn push_item(&self, item: StoredItem) -> Result<(), StorageError> {
+        let pattern = item.pattern.clone();
+        query(
+            "INSERT INTO HexItemStorage (Pattern, Item, Count, Tags, Deletion) VALUES (?,?,?,?,?)",
+        )
+        .bind(item.pattern)
+        .bind(item.item)
+        .bind(item.count)
+        .bind(item.tags.join(","))
+        .bind(item.deletion)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if is_duplicate(&e) {
+                StorageError::AlreadyExists(pattern)
+            } else {
+                e.into()
+            }
+        })?;
+        Ok(())
+    }
+
+    async fn peek_item(&self, pattern: &str) -> Result<StoredItem, StorageError> {
+        let row = query("SELECT * FROM HexItemStorage WHERE Pattern = ?;")
+            .bind(pattern)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))?;
+        Ok(StoredItem {
+            pattern: row.try_get("Pattern")?,
+            item: row.try_get("Item")?,
+            count: row.try_get("Count")?,
+            tags: split_tags(row.try_get("Tags")?),
+            deletion: row.try_get("Deletion")?,
+        })
+    }
+
+    async fn pull_item(&self, seen: &StoredItem) -> Result<(), StorageError> {
+        //only deletes the row if it is still the one that was peeked,
+        //so when two pulls race exactly one of them sees a row go away
+        let res = query(
+            "DELETE FROM HexItemStorage WHERE Pattern = ? AND Item = ? AND Count = ? AND Tags = ?;",
+        )
+        .bind(&seen.pattern)
+        .bind(&seen.item)
+        .bind(seen.count)
+        .bind(seen.tags.join(","))
+        .execute(&self.pool)
+        .await?;
+        if res.rows_affected() == 0 {
+            return Err(StorageError::NotFound(seen.pattern.clone()));
+        }
+        Ok(())
+    }
+
+    async fn prune(&self) -> Result<u64, StorageError> {
+        let iotas = query("DELETE FROM HexDataStorage WHERE Deletion < NOW()")
+            .execute(&self.pool)
+            .await?;
+        let items = query("DELETE FROM HexItemStorage WHERE Deletion < NOW()")
+            .execute(&self.pool)
+            .await?;
+        Ok(iotas.rows_affected() + items.rows_affected())
+    }
+
+    async fn list(&self) -> Result<Vec<StoredIota>, StorageError> {
+        let rows = query("SELECT * FROM `HexDataStorage` ORDER BY Deletion;")
+            .fetch_all(&self.pool)
+            .await?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(StoredIota {
+                    pattern: row.try_get("Pattern")?,
+                    data: row.try_get("Data")?,
+                    password: row.try_get("Password")?,
+                    deletion: row.try_get("Deletion")?,
+                    iota_type: row.try_get("IotaType")?,
+                    sanitized: row.try_get("Sanitized")?,
+                })
+            })
+            .collect()
+    }
+
+    async fn list_patterns(
+        &self,
+        prefix: &str,
+        after: &str,
+        limit: u32,
+    ) -> Result<Vec<String>, StorageError> {
+        list_patterns(&self.pool, prefix, after, limit, NOW).await
+    }
+}
@@ -0,0 +1,304 @@
+use super::{
+    list_patterns, not_matched, split_tags, IotaStat, Storage, StorageError, StoredIota, StoredItem,
+};
+use async_trait::async_trait;
+use sqlx::{
+    query,
+    sqlite::{SqliteConnectOptions, SqlitePool},
+    Row,
+};
+use std::str::FromStr;
+use time::OffsetDateTime;
+
+/// A `HexDataStorage` table in a local SQLite file, for servers that don't want to run MySQL.
+///
+/// `Deletion` is kept as a unix timestamp since SQLite has no real timestamp type
+/// and comparing the text form `time` writes is not reliable.
+#[derive(Debug)]
+pub struct SqliteStorage {
+    pool: SqlitePool,
+}
+
+impl SqliteStorage {
+    pub async fn connect(url: &str) -> Result<Self, StorageError> {
+        let options = SqliteConnectOptions::from_str(url)?.create_if_missing(true);
+        let pool = SqlitePool::connect_with(options).await?;
+        query(
+            "
+        CREATE TABLE IF NOT EXISTS HexDataStorage (
+            Pattern VARCHAR(256) NOT NULL, -- the pattern to lookup db info
+            Data BLOB NOT NULL, -- the NBT data of the object
+            Password BLOB NOT NULL, -- the key to delete this data
+            Deletion INTEGER NOT NULL, -- unix time when this data will be deleted
+            IotaType VARCHAR(256) NOT NULL DEFAULT '', -- hexcasting:type of the top level iota
+            Sanitized BOOLEAN NOT NULL DEFAULT 0, -- whether an entity in it was replaced with garbage
+            PRIMARY KEY (Pattern)
+        );",
+        )
+        .execute(&pool)
+        .await?;
+        //tables made before the iota type was kept don't have the columns yet
+        if query("SELECT IotaType FROM HexDataStorage LIMIT 0;")
+            .fetch_optional(&pool)
+            .await
+            .is_err()
+        {
+            query(
+                "ALTER TABLE HexDataStorage ADD COLUMN IotaType VARCHAR(256) NOT NULL DEFAULT '';",
+            )
+            .execute(&pool)
+            .await?;
+            query("ALTER TABLE HexDataStorage ADD COLUMN Sanitized BOOLEAN NOT NULL DEFAULT 0;")
+                .execute(&pool)
+                .await?;
+        }
+        query(
+            "
+        CREATE TABLE IF NOT EXISTS HexItemStorage (
+            Pattern VARCHAR(256) NOT NULL, -- the pattern the items were pushed to
+            Item BLOB NOT NULL, -- the NBT of the item stack
+            Count INTEGER NOT NULL, -- how many of the item there are, the bits of a u64
+            Tags TEXT NOT NULL, -- comma separated item tags of the item
+            Deletion INTEGER NOT NULL, -- unix time when these items will be deleted
+            PRIMARY KEY (Pattern)
+        );",
+        )
+        .execute(&pool)
+        .await?;
+        Ok(SqliteStorage { pool })
+    }
+}
+
+fn from_unix(timestamp: i64) -> Result<OffsetDateTime, StorageError> {
+    OffsetDateTime::from_unix_timestamp(timestamp)
+        .map_err(|e| StorageError::Backend(format!("invalid deletion time in db: {}", e)))
+}
+
+/// `SQLITE_CONSTRAINT_PRIMARYKEY` and `SQLITE_CONSTRAINT_UNIQUE`, what SQLite says when
+/// the `Pattern` primary key is already taken
+fn is_duplicate(err: &sqlx::Error) -> bool {
+    err.as_database_error()
+        .and_then(|e| e.code())
+        .is_some_and(|code| code == "1555" || code == "2067")
+}
+
+/// the current unix time, what `Deletion` is compared against
+const NOW: &str = "CAST(strftime('%s', 'now') AS INTEGER)";
+
+#[async_trait]
+impl Storage for SqliteStorage {
+    async fn put(&self, iota: StoredIota) -> Result<(), StorageError> {
+        let pattern = iota.pattern.clone();
+        //an expired iota that hasn't been pruned yet doesn't hold on to its pattern
+        query("DELETE FROM HexDataStorage WHERE Pattern = ? AND Deletion < ?;")
+            .bind(&pattern)
+            .bind(OffsetDateTime::now_utc().unix_timestamp())
+            .execute(&self.pool)
+            .await?;
+        query("INSERT INTO HexDataStorage (Pattern, Data, Password, Deletion, IotaType, Sanitized) VALUES (?,?,?,?,?,?)")
+            .bind(iota.pattern)
+            .bind(iota.data)
+            .bind(iota.password)
+            .bind(iota.deletion.unix_timestamp())
+            .bind(iota.iota_type)
+            .bind(iota.sanitized)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                if is_duplicate(&e) {
+                    StorageError::AlreadyExists(pattern)
+                } else {
+                    e.into()
+                }
+            })?;
+        Ok(())
+    }
+
+    async fn get(&self, pattern: &str) -> Result<Vec<u8>, StorageError> {
+        let row = query("SELECT Data FROM HexDataStorage WHERE Pattern = ? AND Deletion >= ?;")
+            .bind(pattern)
+            .bind(OffsetDateTime::now_utc().unix_timestamp())
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))?;
+        Ok(row.try_get("Data")?)
+    }
+
+    async fn stat(&self, pattern: &str) -> Result<IotaStat, StorageError> {
+        let row = query(
+            "SELECT LENGTH(Data) AS Size, IotaType, Sanitized, Deletion FROM HexDataStorage WHERE Pattern = ? AND Deletion >= ?;",
+        )
+        .bind(pattern)
+        .bind(OffsetDateTime::now_utc().unix_timestamp())
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))?;
+        Ok(IotaStat {
+            size: row.try_get::<i64, _>("Size")? as u64,
+            iota_type: row.try_get("IotaType")?,
+            sanitized: row.try_get("Sanitized")?,
+            deletion: from_unix(row.try_get("Deletion")?)?,
+        })
+    }
+
+    async fn replace(
+        &self,
+        pattern: &str,
+        password: &[u8],
+        data: Vec<u8>,
+        iota_type: &str,
+        sanitized: bool,
+        deletion: Option<OffsetDateTime>,
+    ) -> Result<(), StorageError> {
+        let update = match deletion {
+            Some(deletion) => query(
+                "UPDATE HexDataStorage SET Data = ?, IotaType = ?, Sanitized = ?, Deletion = ? WHERE Pattern = ? AND Password = ? AND Deletion >= ?;",
+            )
+            .bind(data)
+            .bind(iota_type)
+            .bind(sanitized)
+            .bind(deletion.unix_timestamp()),
+            None => query("UPDATE HexDataStorage SET Data = ?, IotaType = ?, Sanitized = ? WHERE Pattern = ? AND Password = ? AND Deletion >= ?;")
+                .bind(data)
+                .bind(iota_type)
+                .bind(sanitized),
+        };
+        let res = update
+            .bind(pattern)
+            .bind(password)
+            .bind(OffsetDateTime::now_utc().unix_timestamp())
+            .execute(&self.pool)
+            .await?;
+        if res.rows_affected() > 0 {
+            return Ok(());
+        }
+        Err(not_matched(&self.pool, pattern, NOW).await)
+    }
+
+    async fn refresh(
+        &self,
+        pattern: &str,
+        password: &[u8],
+        deletion: OffsetDateTime,
+    ) -> Result<(), StorageError> {
+        let res =
+            query("UPDATE HexDataStorage SET Deletion = ? WHERE Pattern = ? AND Password = ? AND Deletion >= ?;")
+                .bind(deletion.unix_timestamp())
+                .bind(pattern)
+                .bind(password)
+                .bind(OffsetDateTime::now_utc().unix_timestamp())
+                .execute(&self.pool)
+                .await?;
+        if res.rows_affected() > 0 {
+            return Ok(());
+        }
+        Err(not_matched(&self.pool, pattern, NOW).await)
+    }
+
+    async fn delete(&self, pattern: &str, password: &[u8]) -> Result<(), StorageError> {
+        let res = query("DELETE FROM HexDataStorage WHERE Pattern = ? AND Password = ?;")
+            .bind(pattern)
+            .bind(password)
+            .execute(&self.pool)
+            .await?;
+        if res.rows_affected() > 0 {
+            return Ok(());
+        }
+        Err(not_matched(&self.pool, pattern, NOW).await)
+    }
+
+    async fn push_item(&self, item: StoredItem) -> Result<(), StorageError> {
+        let pattern = item.pattern.clone();
+        query(
+            "INSERT INTO HexItemStorage (Pattern, Item, Count, Tags, Deletion) VALUES (?,?,?,?,?)",
+        )
+        .bind(item.pattern)
+        .bind(item.item)
+        .bind(item.count as i64)
+        .bind(item.tags.join(","))
+        .bind(item.deletion.unix_timestamp())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if is_duplicate(&e) {
+                StorageError::AlreadyExists(pattern)
+            } else {
+                e.into()
+            }
+        })?;
+        Ok(())
+    }
+
+    async fn peek_item(&self, pattern: &str) -> Result<StoredItem, StorageError> {
+        let row = query("SELECT * FROM HexItemStorage WHERE Pattern = ?;")
+            .bind(pattern)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| StorageError::NotFound(pattern.to_owned()))?;
+        Ok(StoredItem {
+            pattern: row.try_get("Pattern")?,
+            item: row.try_get("Item")?,
+            count: row.try_get::<i64, _>("Count")? as u64,
+            tags: split_tags(row.try_get("Tags")?),
+            deletion: from_unix(row.try_get("Deletion")?)?,
+        })
+    }
+
+    async fn pull_item(&self, seen: &StoredItem) -> Result<(), StorageError> {
+        //only deletes the row if it is still the one that was peeked,
+        //so when two pulls race exactly one of them sees a row go away
+        let res = query(
+            "DELETE FROM HexItemStorage WHERE Pattern = ? AND Item = ? AND Count = ? AND Tags = ?;",
+        )
+        .bind(&seen.pattern)
+        .bind(&seen.item)
+        .bind(seen.count as i64)
+        .bind(seen.tags.join(","))
+        .execute(&self.pool)
+        .await?;
+        if res.rows_affected() == 0 {
+            return Err(StorageError::NotFound(seen.pattern.clone()));
+        }
+        Ok(())
+    }
+
+    async fn prune(&self) -> Result<u64, StorageError> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let iotas = query("DELETE FROM HexDataStorage WHERE Deletion < ?")
+            .bind(now)
+            .execute(&self.pool)
+            .await?;
+        let items = query("DELETE FROM HexItemStorage WHERE Deletion < ?")
+            .bind(now)
+            .execute(&self.pool)
+            .await?;
+        Ok(iotas.rows_affected() + items.rows_affected())
+    }
+
+    async fn list(&self) -> Result<Vec<StoredIota>, StorageError> {
+        let rows = query("SELECT * FROM HexDataStorage ORDER BY Deletion;")
+            .fetch_all(&self.pool)
+            .await?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(StoredIota {
+                    pattern: row.try_get("Pattern")?,
+                    data: row.try_get("Data")?,
+                    password: row.try_get("Password")?,
+                    deletion: from_unix(row.try_get("Deletion")?)?,
+                    iota_type: row.try_get("IotaType")?,
+                    sanitized: row.try_get("Sanitized")?,
+                })
+            })
+            .collect()
+    }
+
+    async fn list_patterns(
+        &self,
+        prefix: &str,
+        after: &str,
+        limit: u32,
+    ) -> Result<Vec<String>, StorageError> {
+        list_patterns(&self.pool, prefix, after, limit, NOW).await
+    }
+}
@@ -1,6 +1,14 @@
 #![allow(dead_code)]
 use quartz_nbt::{NbtCompound, NbtList, NbtTag};
 use rand::Rng;
+use std::collections::{BTreeMap, BTreeSet};
+
+use tracing::trace;
+
+use crate::{
+    items::IdList,
+    sanitize::{Action, Limits, SanitizeError, SanitizePolicy, UnknownTypes},
+};
 pub fn generate_random_sig() -> String {
     let mut rng = rand::thread_rng();
     let chars = rng.gen_range(1..=32);
@@ -82,104 +90,375 @@ pub fn generate_random_iota() -> NbtCompound {
 
 #[derive(Default, Debug)]
 pub struct SanatizedNBTResult {
+    /// whether a `hexcasting:entity` iota was replaced with garbage
     pub consumed_entity: bool,
+    /// ids of the item stacks that had their `tag` dropped by `blacklist_drop_nbt`
+    pub stripped_items: Vec<String>,
+    /// how many iotas of each type were replaced with garbage
+    pub replaced: BTreeMap<String, u32>,
+    /// where each garbaged iota was, `$` being the iota that was put,
+    /// `[n]` the n-th iota of a list and `.key` a field of the data
+    pub replaced_paths: Vec<String>,
+    /// iota types without any rules that were passed through as is
+    pub unknown_types: BTreeSet<String>,
     pub resultant_compound: NbtCompound,
 }
 
 impl SanatizedNBTResult {
+    /// the `hexcasting:type` of the sanatized iota itself
+    pub fn iota_type(&self) -> &str {
+        self.resultant_compound
+            .get::<_, &str>("hexcasting:type")
+            .unwrap_or_default()
+    }
+
     fn merge(&mut self, other: SanatizedNBTResult) {
-        self.consumed_entity = self.consumed_entity || other.consumed_entity
-    }
-}
-
-pub fn sanatize_nbt(cta: NbtCompound) -> SanatizedNBTResult {
-    if let Ok(iota_type) = cta.get::<_, &str>("hexcasting:type") {
-        let mut ct = NbtCompound::new();
-        let mut sanatized_entity = false;
-        match iota_type {
-            "hexcasting:list" => {
-                //this can contain other iotas so we gotta sanatize them
-                let res = cta.get::<_, &NbtList>("hexcasting:data");
-                if let Ok(tag) = res {
-                    let mut new_list = NbtList::new();
-                    for iota in tag.iter() {
-                        if let NbtTag::Compound(ctag) = iota {
-                            let san = sanatize_nbt(ctag.clone());
-                            if san.consumed_entity {
-                                sanatized_entity = true
-                            }
-                            new_list.push(NbtTag::Compound(san.resultant_compound));
-                        } else {
-                            new_list.push(iota.clone());
-                        }
-                    }
+        self.consumed_entity = self.consumed_entity || other.consumed_entity;
+        self.stripped_items.extend(other.stripped_items);
+        for (iota_type, count) in other.replaced {
+            *self.replaced.entry(iota_type).or_default() += count;
+        }
+        self.replaced_paths.extend(other.replaced_paths);
+        self.unknown_types.extend(other.unknown_types);
+    }
+}
 
-                    ct.insert("hexcasting:data", new_list);
-                    ct.insert("hexcasting:type", "hexcasting:list")
-                } else {
-                    println!("somehow the data is not a list!!! {}", res.unwrap_err());
-                } //if data is for some reason not a list, ¯\_(ツ)_/¯ Not my problem
+/// Drops the `tag` compound of every item stack (a compound with an `id` and a `Count`)
+/// inside `tag` that `drop_nbt` catches, noting down the id of each one stripped.
+pub fn strip_item_nbt(tag: &mut NbtTag, drop_nbt: &IdList, stripped: &mut Vec<String>) {
+    match tag {
+        NbtTag::Compound(compound) => strip_compound_item_nbt(compound, drop_nbt, stripped),
+        NbtTag::List(list) => {
+            for inner in list.inner_mut().iter_mut() {
+                strip_item_nbt(inner, drop_nbt, stripped);
             }
-            "hexcasting:entity" => {
-                sanatized_entity = true;
-                ct.insert("hexcasting:type", "hexcasting:garbage");
-                ct.insert("hexcasting:data", NbtCompound::new());
-            } //the type we want to specifically fuck over
-            "hextweaks:dict" => {
-                if let Ok(kv) = cta.get::<_, &NbtCompound>("hexcasting:data") {
-                    let mut sanatized_keys = NbtList::new();
-                    let mut sanatized_values = NbtList::new();
-                    if let Ok(keys) = kv.get::<_, &NbtList>("k") {
-                        for iota in keys.iter() {
-                            sanatized_keys.push(if let NbtTag::Compound(datum) = iota {
-                                let san = sanatize_nbt(datum.clone());
-                                if san.consumed_entity {
-                                    sanatized_entity = true
-                                };
-                                NbtTag::Compound(san.resultant_compound)
-                            } else {
-                                iota.clone()
-                            });
-                        }
-                    };
-                    if let Ok(keys) = kv.get::<_, &NbtList>("v") {
-                        for iota in keys.iter() {
-                            sanatized_values.push(if let NbtTag::Compound(datum) = iota {
-                                let san = sanatize_nbt(datum.clone());
-                                if san.consumed_entity {
-                                    sanatized_entity = true
-                                };
-                                NbtTag::Compound(san.resultant_compound)
-                            } else {
-                                iota.clone()
-                            });
-                        }
-                    };
-                    let mut new_kv = NbtCompound::new();
-                    new_kv.insert("k", sanatized_keys);
-                    new_kv.insert("v", sanatized_values);
-                    ct.insert("hexcasting:data", new_kv);
-                    ct.insert("hexcasting:type", "hextweaks:dict");
-                }; //if data is for some reason not a compound, ¯\_(ツ)_/¯ Not my problem
+        }
+        _ => {}
+    }
+}
+
+fn strip_compound_item_nbt(
+    compound: &mut NbtCompound,
+    drop_nbt: &IdList,
+    stripped: &mut Vec<String>,
+) {
+    if let (Ok(id), true) = (
+        compound.get::<_, &str>("id"),
+        compound.contains_key("Count"),
+    ) {
+        if drop_nbt.catches(id) {
+            let id = id.to_owned();
+            if compound.inner_mut().remove("tag").is_some() {
+                stripped.push(id);
             }
-            other => {
-                #[cfg(debug_assertions)]
-                println!("iota type {} does not have any setup sanatization", other);
-                ct.insert("hexcasing:type", other);
-                ct.insert(
-                    "hexcasting:data",
-                    cta.get::<_, &NbtTag>("hexcasting:data").unwrap().clone(),
-                );
-            } //not a type that we filter for/can hold other types
+        }
+    }
+    for inner in compound.inner_mut().values_mut() {
+        strip_item_nbt(inner, drop_nbt, stripped);
+    }
+}
+
+/// the iota everything dangerous gets turned into
+fn garbage() -> NbtCompound {
+    let mut ct = NbtCompound::new();
+    ct.insert("hexcasting:type", "hexcasting:garbage");
+    ct.insert("hexcasting:data", NbtCompound::new());
+    ct
+}
+
+/// follows `path` through nested compounds, starting at `tag`
+fn get_path_mut<'a>(tag: &'a mut NbtTag, path: &[String]) -> Option<&'a mut NbtTag> {
+    path.iter().try_fold(tag, |tag, key| match tag {
+        NbtTag::Compound(compound) => compound.inner_mut().get_mut(key),
+        _ => None,
+    })
+}
+
+/// where the thing at `path` inside the data of the iota at `at` is
+fn join_path(at: &str, path: &[String]) -> String {
+    let mut joined = at.to_owned();
+    for key in path {
+        joined.push('.');
+        joined.push_str(key);
+    }
+    joined
+}
+
+/// Checks the serialized iota against the byte and depth limits before it is read,
+/// reading NBT recurses just like sanitizing it does.
+pub fn check_limits(nbt: &[u8], limits: &Limits) -> Result<(), SanitizeError> {
+    if nbt.len() > limits.max_bytes {
+        return Err(SanitizeError::Oversized {
+            bytes: nbt.len(),
+            max: limits.max_bytes,
+        });
+    }
+    if nbt_too_deep(nbt, limits.max_depth) {
+        return Err(SanitizeError::TooDeep {
+            max: limits.max_depth,
+        });
+    }
+    Ok(())
+}
+
+/// a compound or list the scan is inside of
+enum Nesting {
+    Compound,
+    List { tag_id: u8, left: i32 },
+}
+
+/// just enough of an NBT reader to walk over the tags
+struct NbtCursor<'a>(&'a [u8]);
+
+impl<'a> NbtCursor<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if n > self.0.len() {
+            return None;
+        }
+        let (taken, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Some(taken)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn len16(&mut self) -> Option<usize> {
+        self.take(2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize)
+    }
+
+    fn len32(&mut self) -> Option<i32> {
+        self.take(4)
+            .map(|b| i32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn string(&mut self) -> Option<()> {
+        let len = self.len16()?;
+        self.take(len).map(|_| ())
+    }
+
+    /// the length in bytes of a byte/int/long array with elements of `size` bytes
+    fn array_len(&mut self, size: usize) -> Option<usize> {
+        usize::try_from(self.len32()?).ok()?.checked_mul(size)
+    }
+
+    /// skips the payload of anything that isn't a compound or a list
+    fn skip(&mut self, tag_id: u8) -> Option<()> {
+        let len = match tag_id {
+            1 => 1,
+            2 => 2,
+            3 | 5 => 4,
+            4 | 6 => 8,
+            7 => self.array_len(1)?,
+            11 => self.array_len(4)?,
+            12 => self.array_len(8)?,
+            8 => self.len16()?,
+            _ => return None,
         };
-        SanatizedNBTResult {
-            consumed_entity: sanatized_entity,
-            resultant_compound: ct,
+        self.take(len).map(|_| ())
+    }
+}
+
+/// whether the compounds and lists in `nbt` nest deeper than `max_depth`,
+/// malformed NBT is left for `read_nbt` to complain about
+fn nbt_too_deep(nbt: &[u8], max_depth: usize) -> bool {
+    let mut cursor = NbtCursor(nbt);
+    if cursor.u8() != Some(10) || cursor.string().is_none() {
+        return false;
+    }
+    let mut stack = vec![Nesting::Compound];
+    loop {
+        if stack.len() > max_depth {
+            return true;
         }
-    } else {
-        SanatizedNBTResult {
-            consumed_entity: false,
-            resultant_compound: cta,
+        let tag_id = match stack.last_mut() {
+            None => return false,
+            Some(Nesting::Compound) => match cursor.u8() {
+                None => return false,
+                Some(0) => {
+                    stack.pop();
+                    continue;
+                }
+                Some(tag_id) => {
+                    if cursor.string().is_none() {
+                        return false;
+                    }
+                    tag_id
+                }
+            },
+            Some(Nesting::List { left, .. }) if *left <= 0 => {
+                stack.pop();
+                continue;
+            }
+            Some(Nesting::List { tag_id, left }) => {
+                *left -= 1;
+                *tag_id
+            }
+        };
+        let nested = match tag_id {
+            9 => match (cursor.u8(), cursor.len32()) {
+                (Some(tag_id), Some(left)) => Nesting::List { tag_id, left },
+                _ => return false,
+            },
+            10 => Nesting::Compound,
+            tag_id => {
+                if cursor.skip(tag_id).is_none() {
+                    return false;
+                }
+                continue;
+            }
+        };
+        stack.push(nested);
+    }
+}
+
+/// sanatizes every iota in `list` (found at `at`) in place
+fn sanatize_list(
+    list: &mut NbtList,
+    policy: &SanitizePolicy,
+    at: &str,
+    iotas: &mut usize,
+    result: &mut SanatizedNBTResult,
+) -> Result<(), SanitizeError> {
+    for (i, iota) in list.inner_mut().iter_mut().enumerate() {
+        let at = format!("{}[{}]", at, i);
+        let NbtTag::Compound(ctag) = iota else {
+            return Err(SanitizeError::NotAnIota { path: at });
+        };
+        let mut san = sanatize_iota(std::mem::take(ctag), policy, &at, iotas)?;
+        *ctag = std::mem::take(&mut san.resultant_compound);
+        result.merge(san);
+    }
+    Ok(())
+}
+
+/// Applies the rules in `policy` for the iota's type (and for any iotas inside it),
+/// giving up once there are more iotas than `policy.limits` allows or on anything
+/// that isn't shaped like an iota.
+pub fn sanatize_nbt(
+    cta: NbtCompound,
+    policy: &SanitizePolicy,
+) -> Result<SanatizedNBTResult, SanitizeError> {
+    sanatize_nbt_counted(cta, policy, &mut 0)
+}
+
+/// [`sanatize_nbt`] for one of several iotas that share the `max_iotas` limit,
+/// `iotas` counting the ones seen so far across all of them.
+pub fn sanatize_nbt_counted(
+    cta: NbtCompound,
+    policy: &SanitizePolicy,
+    iotas: &mut usize,
+) -> Result<SanatizedNBTResult, SanitizeError> {
+    let mut result = sanatize_iota(cta, policy, "$", iotas)?;
+    strip_compound_item_nbt(
+        &mut result.resultant_compound,
+        &policy.drop_nbt,
+        &mut result.stripped_items,
+    );
+    Ok(result)
+}
+
+fn wrong_shape(iota_type: &str, path: String, expected: &'static str) -> SanitizeError {
+    SanitizeError::WrongShape {
+        iota_type: iota_type.to_owned(),
+        path,
+        expected,
+    }
+}
+
+/// the rules for unknown types when they get garbaged
+const GARBAGE: &[Action] = &[Action::Garbage];
+
+/// [`sanatize_nbt`] for the iota at `at`, `iotas` counting the ones seen so far
+fn sanatize_iota(
+    mut cta: NbtCompound,
+    policy: &SanitizePolicy,
+    at: &str,
+    iotas: &mut usize,
+) -> Result<SanatizedNBTResult, SanitizeError> {
+    let mut result = SanatizedNBTResult::default();
+    let Ok(iota_type) = cta.get::<_, &str>("hexcasting:type") else {
+        return Err(SanitizeError::NotAnIota {
+            path: at.to_owned(),
+        });
+    };
+    let iota_type = iota_type.to_owned();
+    let Some(mut data) = cta.inner_mut().remove("hexcasting:data") else {
+        return Err(SanitizeError::MissingData {
+            iota_type,
+            path: at.to_owned(),
+        });
+    };
+    *iotas += 1;
+    if *iotas > policy.limits.max_iotas {
+        return Err(SanitizeError::TooManyIotas {
+            max: policy.limits.max_iotas,
+        });
+    }
+    let actions = match policy.rules.get(&iota_type) {
+        Some(actions) => actions.as_slice(),
+        None => match policy.unknown_types {
+            UnknownTypes::Pass => {
+                trace!(
+                    "iota type {} does not have any setup sanatization",
+                    iota_type
+                );
+                result.unknown_types.insert(iota_type.clone());
+                &[]
+            }
+            UnknownTypes::Garbage => GARBAGE,
+            UnknownTypes::Reject => {
+                return Err(SanitizeError::UnknownType {
+                    iota_type,
+                    path: at.to_owned(),
+                })
+            }
+        },
+    };
+    for action in actions {
+        match action {
+            Action::Pass => {}
+            Action::Garbage => {
+                result.consumed_entity |= iota_type == "hexcasting:entity";
+                *result.replaced.entry(iota_type).or_default() += 1;
+                result.replaced_paths.push(at.to_owned());
+                result.resultant_compound = garbage();
+                return Ok(result);
+            }
+            Action::RecurseList { path } => {
+                let at = join_path(at, path);
+                let Some(NbtTag::List(list)) = get_path_mut(&mut data, path) else {
+                    return Err(wrong_shape(&iota_type, at, "list"));
+                };
+                sanatize_list(list, policy, &at, iotas, &mut result)?;
+            }
+            Action::RecurseDict { path, keys, values } => {
+                let Some(NbtTag::Compound(dict)) = get_path_mut(&mut data, path) else {
+                    return Err(wrong_shape(&iota_type, join_path(at, path), "compound"));
+                };
+                for key in [keys, values] {
+                    let at = join_path(at, &[path.as_slice(), std::slice::from_ref(key)].concat());
+                    let Some(NbtTag::List(list)) = dict.inner_mut().get_mut(key) else {
+                        return Err(wrong_shape(&iota_type, at, "list"));
+                    };
+                    sanatize_list(list, policy, &at, iotas, &mut result)?;
+                }
+            }
+            Action::DropField { path } => {
+                let Some((field, parent)) = path.split_last() else {
+                    continue;
+                };
+                if let Some(NbtTag::Compound(parent)) = get_path_mut(&mut data, parent) {
+                    parent.inner_mut().remove(field);
+                }
+            }
         }
     }
+    let mut ct = NbtCompound::new();
+    ct.insert("hexcasting:type", iota_type);
+    ct.insert("hexcasting:data", data);
+    result.resultant_compound = ct;
+    Ok(result)
 }
@@ -0,0 +1,63 @@
+#[path = "../src/frame.rs"]
+mod frame;
+
+use frame::{read_frame, write_frame, FrameError};
+use tokio::io::{duplex, AsyncWriteExt};
+
+#[tokio::test]
+async fn round_trip() {
+    let (mut client, mut server) = duplex(1024);
+    write_frame(&mut client, b"hello overmind").await.unwrap();
+    write_frame(&mut client, b"").await.unwrap();
+    drop(client);
+    assert_eq!(
+        read_frame(&mut server, 1024).await.unwrap().unwrap(),
+        b"hello overmind"
+    );
+    assert_eq!(read_frame(&mut server, 1024).await.unwrap().unwrap(), b"");
+    assert!(read_frame(&mut server, 1024).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn clean_eof() {
+    let (client, mut server) = duplex(64);
+    drop(client);
+    assert!(read_frame(&mut server, 1024).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn truncated() {
+    //closed part way through the length prefix
+    let (mut client, mut server) = duplex(64);
+    client.write_all(&[5, 0]).await.unwrap();
+    drop(client);
+    assert!(matches!(
+        read_frame(&mut server, 1024).await,
+        Err(FrameError::Truncated)
+    ));
+    //and part way through the frame itself
+    let (mut client, mut server) = duplex(64);
+    client.write_all(&[5, 0, 0, 0, 1, 2]).await.unwrap();
+    drop(client);
+    assert!(matches!(
+        read_frame(&mut server, 1024).await,
+        Err(FrameError::Truncated)
+    ));
+}
+
+#[tokio::test]
+async fn too_large() {
+    let (mut client, mut server) = duplex(64);
+    client.write_all(&1025u32.to_le_bytes()).await.unwrap();
+    assert!(matches!(
+        read_frame(&mut server, 1024).await,
+        Err(FrameError::TooLarge(1025))
+    ));
+    //exactly the maximum is fine
+    let (mut client, mut server) = duplex(2048);
+    write_frame(&mut client, &[7; 1024]).await.unwrap();
+    assert_eq!(
+        read_frame(&mut server, 1024).await.unwrap().unwrap(),
+        [7; 1024]
+    );
+}
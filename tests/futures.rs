@@ -0,0 +1,97 @@
+#[path = "../src/futures.rs"]
+mod futures;
+
+use futures::{FutureError, Futures};
+use time::{Duration, OffsetDateTime};
+
+fn later() -> OffsetDateTime {
+    OffsetDateTime::now_utc() + Duration::hours(1)
+}
+
+#[test]
+fn lifecycle() {
+    let mut futures = Futures::new(4);
+    let (index, uuid) = futures.create(vec![1; 16], later()).unwrap();
+    assert!(!futures.poll(index, &uuid).unwrap());
+    assert!(matches!(
+        futures.fetch(index, &uuid),
+        Err(FutureError::NotDone)
+    ));
+    assert!(matches!(
+        futures.complete(index, &uuid, &[2; 16], vec![]),
+        Err(FutureError::WrongPassword)
+    ));
+    let result = vec![vec![10, 0, 0, 0], vec![10, 1, 0, 0]];
+    futures
+        .complete(index, &uuid, &[1; 16], result.clone())
+        .unwrap();
+    assert!(futures.poll(index, &uuid).unwrap());
+    assert_eq!(futures.fetch(index, &uuid).unwrap(), result.as_slice());
+    assert!(matches!(
+        futures.complete(index, &uuid, &[1; 16], vec![]),
+        Err(FutureError::AlreadyCompleted)
+    ));
+    //the result doesn't change after a second complete
+    assert_eq!(futures.fetch(index, &uuid).unwrap(), result.as_slice());
+}
+
+#[test]
+fn unknown_handles() {
+    let mut futures = Futures::new(4);
+    let (index, uuid) = futures.create(vec![1; 16], later()).unwrap();
+    assert!(matches!(
+        futures.poll(index + 1, &uuid),
+        Err(FutureError::Gone)
+    ));
+    assert!(matches!(
+        futures.poll(index, &[0; 16]),
+        Err(FutureError::Gone)
+    ));
+}
+
+#[test]
+fn reused_slot() {
+    let mut futures = Futures::new(1);
+    let expired = OffsetDateTime::now_utc() - Duration::seconds(1);
+    let (old_index, old_uuid) = futures.create(vec![1; 16], expired).unwrap();
+    assert!(matches!(
+        futures.poll(old_index, &old_uuid),
+        Err(FutureError::Gone)
+    ));
+    //the expired slot is handed to the next future
+    let (index, uuid) = futures.create(vec![2; 16], later()).unwrap();
+    assert_eq!(index, old_index);
+    assert_ne!(uuid, old_uuid);
+    assert!(!futures.poll(index, &uuid).unwrap());
+    //and the old handle can't touch the new future
+    assert!(matches!(
+        futures.poll(old_index, &old_uuid),
+        Err(FutureError::Gone)
+    ));
+    assert!(matches!(
+        futures.complete(old_index, &old_uuid, &[1; 16], vec![]),
+        Err(FutureError::Gone)
+    ));
+}
+
+#[test]
+fn full() {
+    let mut futures = Futures::new(2);
+    futures.create(vec![1; 16], later()).unwrap();
+    futures.create(vec![1; 16], later()).unwrap();
+    assert!(matches!(
+        futures.create(vec![1; 16], later()),
+        Err(FutureError::Full)
+    ));
+}
+
+#[test]
+fn prune() {
+    let mut futures = Futures::new(4);
+    let expired = OffsetDateTime::now_utc() - Duration::seconds(1);
+    let (index, uuid) = futures.create(vec![1; 16], later()).unwrap();
+    futures.create(vec![1; 16], expired).unwrap();
+    assert_eq!(futures.prune(), 1);
+    assert_eq!(futures.prune(), 0);
+    assert!(futures.poll(index, &uuid).is_ok());
+}
@@ -0,0 +1,48 @@
+#[path = "../src/items.rs"]
+mod items;
+
+use items::{is_resource_location, IdList, ItemPolicy};
+
+#[test]
+fn resource_locations() {
+    for tag in ["minecraft:logs", "c:ingots/iron", "my_mod:a-b.c"] {
+        assert!(is_resource_location(tag), "{}", tag);
+    }
+    for tag in [
+        "",
+        "logs",
+        ":logs",
+        "minecraft:",
+        "a,b:c",
+        "x,minecraft:logs",
+        "minecraft:logs,",
+        "Minecraft:logs",
+        "minecraft:lo gs",
+        "#minecraft:logs",
+    ] {
+        assert!(!is_resource_location(tag), "{}", tag);
+    }
+}
+
+#[test]
+fn tag_lists() {
+    let policy = ItemPolicy {
+        tags: IdList::new(["minecraft:logs".to_owned()], false),
+        ..ItemPolicy::default()
+    };
+    let tags = |tags: &[&str]| tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+    assert!(policy.check("minecraft:stick", &tags(&["c:rods"])).is_ok());
+    let rejection = policy
+        .check("minecraft:oak_log", &tags(&["c:wood", "#minecraft:logs"]))
+        .unwrap_err();
+    assert_eq!(rejection.tag.as_deref(), Some("minecraft:logs"));
+
+    let whitelist = ItemPolicy {
+        tags: IdList::new(["minecraft:logs".to_owned()], true),
+        ..ItemPolicy::default()
+    };
+    assert!(whitelist
+        .check("minecraft:oak_log", &tags(&["minecraft:logs"]))
+        .is_ok());
+    assert!(whitelist.check("minecraft:stick", &tags(&[])).is_err());
+}
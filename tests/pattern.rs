@@ -0,0 +1,122 @@
+#[path = "../src/pattern.rs"]
+mod pattern;
+
+use pattern::{canonicalize, reverse, validate, validate_angles, PatternError, PatternPolicy};
+
+#[test]
+fn invalid_chars() {
+    assert_eq!(validate("qaq"), Ok(()));
+    assert_eq!(
+        validate("qa q"),
+        Err(PatternError::InvalidChar {
+            index: 2,
+            found: ' '
+        })
+    );
+    assert!(validate("QAQ").is_err());
+    assert!(PatternPolicy::default().storage_key("wxw", None).is_err());
+}
+
+#[test]
+fn drawable() {
+    for sig in ["w", "qaq", "aadaa", "wqaawdd", "eeeee", "qwaeawqaeaqa"] {
+        assert_eq!(validate(sig), Ok(()), "{}", sig);
+    }
+    assert_eq!(validate(""), Err(PatternError::Empty));
+    assert_eq!(validate(&"w".repeat(256)), Ok(()));
+    assert_eq!(
+        validate(&"w".repeat(257)),
+        Err(PatternError::TooLong { len: 257, max: 256 })
+    );
+}
+
+#[test]
+fn retraced_edges() {
+    //going back is always over the stroke just drawn
+    assert_eq!(validate("s"), Err(PatternError::RetracesEdge { index: 0 }));
+    assert_eq!(
+        validate("wws"),
+        Err(PatternError::RetracesEdge { index: 2 })
+    );
+    //a full hexagon closes on the first stroke
+    assert_eq!(
+        validate("eeeeee"),
+        Err(PatternError::RetracesEdge { index: 5 })
+    );
+    assert_eq!(
+        validate(&reverse("eeeeee")),
+        Err(PatternError::RetracesEdge { index: 5 })
+    );
+    //crossing a point is fine as long as no edge is drawn twice
+    assert_eq!(validate("qqqqqwqqqqq"), Ok(()));
+}
+
+#[test]
+fn reversed_traversal() {
+    //mind's reflection drawn from either end
+    assert_eq!(reverse("qaq"), "ede");
+    assert_eq!(reverse("wqaawdd"), "aawddew");
+    for sig in ["qaq", "aadaa", "wqaawdd", "eeeee", "w"] {
+        assert_eq!(reverse(&reverse(sig)), sig);
+    }
+}
+
+#[test]
+fn canonical_keys() {
+    for sig in ["qaq", "aadaa", "wqaawdd", "qwaeawqaeaqa", ""] {
+        let key = canonicalize(sig);
+        assert_eq!(key, canonicalize(&reverse(sig)));
+        assert_eq!(canonicalize(&key), key);
+        assert!(key == sig || key == reverse(sig));
+    }
+    let mut policy = PatternPolicy::default();
+    assert_eq!(policy.storage_key("qaq", None).unwrap(), "qaq");
+    policy.canonical = true;
+    assert_eq!(policy.storage_key("ede", None).unwrap(), "ede");
+    assert_eq!(policy.storage_key("qaq", None).unwrap(), "ede");
+    assert_eq!(policy.storage_key("qaq", Some("srv")).unwrap(), "srv/ede");
+}
+
+#[test]
+fn namespaces() {
+    let policy = PatternPolicy::default();
+    assert_eq!(policy.storage_key("qaq", Some("")).unwrap(), "qaq");
+    assert_eq!(policy.storage_key("qaq", Some("global")).unwrap(), "qaq");
+    assert_eq!(
+        policy.storage_key("qaq", Some("smp-1:red")).unwrap(),
+        "smp-1:red/qaq"
+    );
+    assert_eq!(
+        policy.storage_key("qaq", Some("Team")).unwrap(),
+        policy.storage_key("qaq", Some("team")).unwrap()
+    );
+    assert_eq!(policy.storage_key("qaq", Some("GLOBAL")).unwrap(), "qaq");
+    for bad in ["a/b", "with space", &"n".repeat(65)] {
+        assert_eq!(
+            policy.storage_key("qaq", Some(bad)),
+            Err(PatternError::InvalidNamespace {
+                namespace: bad.to_owned()
+            })
+        );
+    }
+    //the namespace eats into the room left for the pattern
+    let long = "w".repeat(250);
+    assert!(policy.storage_key(&long, None).is_ok());
+    assert_eq!(
+        policy.storage_key(&long, Some("server")),
+        Err(PatternError::TooLong { len: 250, max: 249 })
+    );
+}
+
+#[test]
+fn list_prefixes() {
+    let policy = PatternPolicy::default();
+    assert_eq!(policy.key_prefix(None).unwrap(), "");
+    assert_eq!(policy.key_prefix(Some("global")).unwrap(), "");
+    assert_eq!(policy.key_prefix(Some("srv")).unwrap(), "srv/");
+    assert!(policy.key_prefix(Some("a/b")).is_err());
+    //prefixes only need to be angles, not something that can be drawn
+    assert_eq!(validate_angles(""), Ok(()));
+    assert_eq!(validate_angles("ss"), Ok(()));
+    assert!(validate_angles("q/").is_err());
+}
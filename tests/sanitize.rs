@@ -0,0 +1,434 @@
+//! Tests for `sanatize_nbt` against the corpus of real iota NBT in `tests/corpus`
+//! (one SNBT iota per file) and against randomly generated iotas.
+#[path = "../src/items.rs"]
+mod items;
+#[path = "../src/sanitize.rs"]
+mod sanitize;
+#[path = "../src/util.rs"]
+mod util;
+
+use quartz_nbt::{
+    compound,
+    io::{write_nbt, Flavor},
+    snbt, NbtCompound, NbtList, NbtTag,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{fs, path::Path};
+
+use items::IdList;
+use sanitize::{Limits, SanitizeError, SanitizePolicy, UnknownTypes};
+use util::{check_limits, sanatize_nbt, sanatize_nbt_counted, strip_item_nbt};
+
+fn corpus() -> Vec<(String, NbtCompound)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut corpus = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let iota = snbt::parse(&fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|e| panic!("{} is not valid SNBT: {}", name, e));
+            (name, iota)
+        })
+        .collect::<Vec<_>>();
+    corpus.sort_by(|a, b| a.0.cmp(&b.0));
+    assert!(!corpus.is_empty());
+    corpus
+}
+
+fn garbage() -> NbtCompound {
+    compound! {"hexcasting:type": "hexcasting:garbage", "hexcasting:data": {}}
+}
+
+/// what the default rules should turn `tag` into, every entity anywhere becoming garbage
+fn without_entities(tag: &NbtTag) -> (NbtTag, u32) {
+    match tag {
+        NbtTag::Compound(compound)
+            if matches!(
+                compound.get::<_, &str>("hexcasting:type"),
+                Ok("hexcasting:entity")
+            ) =>
+        {
+            (NbtTag::Compound(garbage()), 1)
+        }
+        NbtTag::Compound(compound) => {
+            let mut entities = 0;
+            let mut out = NbtCompound::new();
+            for (key, inner) in compound.inner() {
+                let (inner, found) = without_entities(inner);
+                entities += found;
+                out.insert(key.clone(), inner);
+            }
+            (NbtTag::Compound(out), entities)
+        }
+        NbtTag::List(list) => {
+            let mut entities = 0;
+            let mut out = NbtList::new();
+            for inner in list.iter() {
+                let (inner, found) = without_entities(inner);
+                entities += found;
+                out.push(inner);
+            }
+            (NbtTag::List(out), entities)
+        }
+        other => (other.clone(), 0),
+    }
+}
+
+fn has_entity(tag: &NbtTag) -> bool {
+    without_entities(tag).1 > 0
+}
+
+#[test]
+fn corpus_matches_model() {
+    let policy = SanitizePolicy::default();
+    for (name, iota) in corpus() {
+        let (expected, entities) = without_entities(&NbtTag::Compound(iota.clone()));
+        let san = sanatize_nbt(iota, &policy).unwrap();
+        assert_eq!(
+            NbtTag::Compound(san.resultant_compound),
+            expected,
+            "{} was not sanitized as expected",
+            name
+        );
+        let replaced = san.replaced.get("hexcasting:entity").copied().unwrap_or(0);
+        assert_eq!(replaced, entities, "{} reported the wrong count", name);
+        assert_eq!(san.replaced_paths.len() as u32, entities, "{}", name);
+        assert_eq!(san.consumed_entity, entities > 0, "{}", name);
+    }
+}
+
+#[test]
+fn corpus_types_preserved() {
+    let policy = SanitizePolicy::default();
+    for (name, iota) in corpus() {
+        if has_entity(&NbtTag::Compound(iota.clone())) {
+            continue;
+        }
+        let san = sanatize_nbt(iota.clone(), &policy).unwrap();
+        assert_eq!(san.resultant_compound, iota, "{} was changed", name);
+        assert!(san.replaced.is_empty(), "{}", name);
+    }
+}
+
+#[test]
+fn corpus_idempotent() {
+    let policy = SanitizePolicy::default();
+    for (name, iota) in corpus() {
+        let once = sanatize_nbt(iota, &policy).unwrap().resultant_compound;
+        let twice = sanatize_nbt(once.clone(), &policy).unwrap();
+        assert_eq!(twice.resultant_compound, once, "{} is not idempotent", name);
+        assert!(twice.replaced.is_empty(), "{}", name);
+    }
+}
+
+#[test]
+fn corpus_within_limits() {
+    for (name, iota) in corpus() {
+        let mut bytes = vec![];
+        write_nbt(&mut bytes, None, &iota, Flavor::Uncompressed).unwrap();
+        assert_eq!(
+            check_limits(&bytes, &Limits::default()),
+            Ok(()),
+            "{} was refused",
+            name
+        );
+    }
+}
+
+#[test]
+fn replaced_paths() {
+    let (_, dict) = corpus()
+        .into_iter()
+        .find(|(name, _)| name == "dict")
+        .unwrap();
+    let san = sanatize_nbt(dict, &SanitizePolicy::default()).unwrap();
+    assert_eq!(san.replaced_paths, ["$.k[1]", "$.v[0][0]"]);
+}
+
+#[test]
+fn unknown_types() {
+    let iota = compound! {"hexcasting:type": "moreiotas:string", "hexcasting:data": "hi"};
+    let mut policy = SanitizePolicy::default();
+
+    let san = sanatize_nbt(iota.clone(), &policy).unwrap();
+    //used to be written back as "hexcasing:type"
+    assert_eq!(san.resultant_compound, iota);
+    assert!(san.unknown_types.contains("moreiotas:string"));
+
+    policy.unknown_types = UnknownTypes::Garbage;
+    let san = sanatize_nbt(iota.clone(), &policy).unwrap();
+    assert_eq!(san.resultant_compound, garbage());
+    assert_eq!(san.replaced.get("moreiotas:string"), Some(&1));
+    assert!(!san.consumed_entity);
+
+    policy.unknown_types = UnknownTypes::Reject;
+    assert_eq!(
+        sanatize_nbt(iota, &policy).unwrap_err(),
+        SanitizeError::UnknownType {
+            iota_type: "moreiotas:string".to_owned(),
+            path: "$".to_owned()
+        }
+    );
+}
+
+#[test]
+fn shipped_rules_match_built_in() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("sanitize_rules.json");
+    let rules = SanitizePolicy::load_rules(&path).unwrap();
+    assert_eq!(rules, SanitizePolicy::default().rules);
+}
+
+#[test]
+fn malformed_iotas() {
+    let policy = SanitizePolicy::default();
+    let list = "hexcasting:list".to_owned();
+    let dict = "hextweaks:dict".to_owned();
+    let not_an_iota = |path: &str| SanitizeError::NotAnIota {
+        path: path.to_owned(),
+    };
+    let wrong_shape = |iota_type: &String, path: &str, expected| SanitizeError::WrongShape {
+        iota_type: iota_type.clone(),
+        path: path.to_owned(),
+        expected,
+    };
+    let malformed = [
+        (
+            compound! {"hexcasting:type": "hexcasting:list"},
+            SanitizeError::MissingData {
+                iota_type: list.clone(),
+                path: "$".to_owned(),
+            },
+        ),
+        (
+            compound! {"hexcasting:type": "hexcasting:list", "hexcasting:data": [
+                {"hexcasting:type": "hexcasting:list"}
+            ]},
+            SanitizeError::MissingData {
+                iota_type: list.clone(),
+                path: "$[0]".to_owned(),
+            },
+        ),
+        (
+            compound! {"hexcasting:type": "hexcasting:list", "hexcasting:data": 5},
+            wrong_shape(&list, "$", "list"),
+        ),
+        (
+            compound! {"hexcasting:type": "hexcasting:list", "hexcasting:data": [1, 2, 3]},
+            not_an_iota("$[0]"),
+        ),
+        (
+            compound! {"hexcasting:type": "hexcasting:list", "hexcasting:data": ["a", "b"]},
+            not_an_iota("$[0]"),
+        ),
+        (
+            compound! {"hexcasting:type": "hextweaks:dict", "hexcasting:data": "k"},
+            wrong_shape(&dict, "$", "compound"),
+        ),
+        (
+            compound! {"hexcasting:type": "hextweaks:dict", "hexcasting:data": {"k": 1, "v": [[]]}},
+            wrong_shape(&dict, "$.k", "list"),
+        ),
+        (
+            compound! {"hexcasting:type": 7, "hexcasting:data": {}},
+            not_an_iota("$"),
+        ),
+        (
+            compound! {"hexcasting:data": [{"hexcasting:type": "hexcasting:entity"}]},
+            not_an_iota("$"),
+        ),
+        (NbtCompound::new(), not_an_iota("$")),
+    ];
+    for (iota, expected) in malformed {
+        assert_eq!(sanatize_nbt(iota, &policy).unwrap_err(), expected);
+    }
+
+    //entities become garbage whatever their data looks like
+    let entity = compound! {"hexcasting:type": "hexcasting:entity", "hexcasting:data": 0.5};
+    let san = sanatize_nbt(entity, &policy).unwrap();
+    assert_eq!(san.resultant_compound, garbage());
+}
+
+/// a random iota, sometimes with data of the wrong shape, and whether it is well formed
+fn random_iota(rng: &mut StdRng, depth: u32) -> (NbtCompound, bool) {
+    let nested = depth < 4;
+    let mut well_formed = true;
+    let (iota_type, data): (&str, NbtTag) = match rng.gen_range(0..10) {
+        0 => ("hexcasting:double", NbtTag::Double(rng.gen())),
+        1 => (
+            "hexcasting:pattern",
+            compound! {"start_dir": rng.gen_range(0i8..6), "angles": vec![0i8, 1, 4]}.into(),
+        ),
+        2 => (
+            "hexcasting:entity",
+            compound! {"name": "{\"text\":\"x\"}", "uuid": vec![rng.gen::<i32>(); 4]}.into(),
+        ),
+        3 | 4 if nested => {
+            let mut list = NbtList::new();
+            for _ in 0..rng.gen_range(0..4) {
+                let (iota, ok) = random_iota(rng, depth + 1);
+                well_formed &= ok;
+                list.push(iota);
+            }
+            ("hexcasting:list", list.into())
+        }
+        5 if nested => {
+            let mut keys = NbtList::new();
+            let mut values = NbtList::new();
+            for _ in 0..rng.gen_range(0..3) {
+                for list in [&mut keys, &mut values] {
+                    let (iota, ok) = random_iota(rng, depth + 1);
+                    well_formed &= ok;
+                    list.push(iota);
+                }
+            }
+            ("hextweaks:dict", compound! {"k": keys, "v": values}.into())
+        }
+        6 => ("moreiotas:string", NbtTag::String("hi".to_owned())),
+        //the right type with the wrong data
+        7 => {
+            well_formed = false;
+            ("hexcasting:list", NbtTag::Int(rng.gen()))
+        }
+        8 => {
+            well_formed = false;
+            ("hextweaks:dict", NbtList::from(vec![1i32, 2]).into())
+        }
+        _ => ("hexcasting:null", NbtCompound::new().into()),
+    };
+    let mut iota = NbtCompound::new();
+    iota.insert("hexcasting:type", iota_type);
+    iota.insert("hexcasting:data", data);
+    (iota, well_formed)
+}
+
+#[test]
+fn random_iotas() {
+    let policy = SanitizePolicy::default();
+    for seed in 0..1000 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (iota, well_formed) = random_iota(&mut rng, 0);
+        let Ok(san) = sanatize_nbt(iota, &policy) else {
+            assert!(!well_formed, "seed {} was refused", seed);
+            continue;
+        };
+        assert!(well_formed, "seed {} was let through", seed);
+        let once = san.resultant_compound;
+        assert!(
+            !has_entity(&NbtTag::Compound(once.clone())),
+            "seed {} let an entity through",
+            seed
+        );
+        let twice = sanatize_nbt(once.clone(), &policy).unwrap();
+        assert_eq!(twice.resultant_compound, once, "seed {}", seed);
+    }
+}
+
+#[test]
+fn limits() {
+    let mut list = NbtList::new();
+    for _ in 0..10 {
+        list.push(compound! {"hexcasting:type": "hexcasting:null", "hexcasting:data": {}});
+    }
+    let iota = compound! {"hexcasting:type": "hexcasting:list", "hexcasting:data": list};
+    let mut policy = SanitizePolicy::default();
+    policy.limits.max_iotas = 11;
+    assert!(sanatize_nbt(iota.clone(), &policy).is_ok());
+    policy.limits.max_iotas = 10;
+    assert_eq!(
+        sanatize_nbt(iota.clone(), &policy).unwrap_err(),
+        SanitizeError::TooManyIotas { max: 10 }
+    );
+
+    //iotas sharing a counter (a future's result) share the limit too
+    policy.limits.max_iotas = 15;
+    let mut iotas = 0;
+    assert!(sanatize_nbt_counted(iota.clone(), &policy, &mut iotas).is_ok());
+    assert_eq!(iotas, 11);
+    assert_eq!(
+        sanatize_nbt_counted(iota.clone(), &policy, &mut iotas).unwrap_err(),
+        SanitizeError::TooManyIotas { max: 15 }
+    );
+
+    let mut bytes = vec![];
+    write_nbt(&mut bytes, None, &iota, Flavor::Uncompressed).unwrap();
+    let limits = Limits {
+        max_bytes: bytes.len() - 1,
+        ..Limits::default()
+    };
+    assert!(matches!(
+        check_limits(&bytes, &limits),
+        Err(SanitizeError::Oversized { .. })
+    ));
+
+    //the root compound, the list, the iotas in it and their data make 4 levels
+    let limits = Limits {
+        max_depth: 4,
+        ..Limits::default()
+    };
+    assert_eq!(check_limits(&bytes, &limits), Ok(()));
+    let limits = Limits {
+        max_depth: 3,
+        ..Limits::default()
+    };
+    assert_eq!(
+        check_limits(&bytes, &limits),
+        Err(SanitizeError::TooDeep { max: 3 })
+    );
+}
+
+fn item_stacks() -> NbtCompound {
+    compound! {"hexcasting:type": "hexcasting:list", "hexcasting:data": [
+        {"hexcasting:type": "moreiotas:item_stack", "hexcasting:data": {
+            "id": "minecraft:written_book", "Count": 1i8, "tag": {"pages": ["hi"]}
+        }},
+        {"hexcasting:type": "moreiotas:item_stack", "hexcasting:data": {
+            "id": "minecraft:stick", "Count": 1i8, "tag": {"x": 1}
+        }}
+    ]}
+}
+
+/// whether the item stack in the n-th iota of [`item_stacks`] still has its `tag`
+fn has_tag(iota: &NbtCompound, n: usize) -> bool {
+    let list = iota.get::<_, &NbtList>("hexcasting:data").unwrap();
+    let NbtTag::Compound(stack) = &list[n] else {
+        panic!("not an iota");
+    };
+    stack
+        .get::<_, &NbtCompound>("hexcasting:data")
+        .unwrap()
+        .contains_key("tag")
+}
+
+#[test]
+fn drop_item_nbt() {
+    let mut tag = NbtTag::Compound(item_stacks());
+    let mut stripped = vec![];
+    let blacklist = IdList::new(["minecraft:written_book".to_owned()], false);
+    strip_item_nbt(&mut tag, &blacklist, &mut stripped);
+    assert_eq!(stripped, ["minecraft:written_book"]);
+    let NbtTag::Compound(iota) = &tag else {
+        unreachable!()
+    };
+    assert!(!has_tag(iota, 0));
+    assert!(has_tag(iota, 1));
+    //nothing left to strip the second time
+    stripped.clear();
+    strip_item_nbt(&mut tag, &blacklist, &mut stripped);
+    assert!(stripped.is_empty());
+
+    //an inverted list strips everything it doesn't name
+    let policy = SanitizePolicy {
+        drop_nbt: IdList::new(["minecraft:written_book".to_owned()], true),
+        ..SanitizePolicy::default()
+    };
+    let san = sanatize_nbt(item_stacks(), &policy).unwrap();
+    assert_eq!(san.stripped_items, ["minecraft:stick"]);
+    assert!(has_tag(&san.resultant_compound, 0));
+    assert!(!has_tag(&san.resultant_compound, 1));
+
+    //and the default strips nothing
+    let san = sanatize_nbt(item_stacks(), &SanitizePolicy::default()).unwrap();
+    assert!(san.stripped_items.is_empty());
+    assert_eq!(san.resultant_compound, item_stacks());
+}
@@ -0,0 +1,290 @@
+#[path = "../src/storage/mod.rs"]
+mod storage;
+
+use quartz_nbt::{
+    io::{write_nbt, Flavor},
+    NbtCompound, NbtList,
+};
+use sqlx::{
+    query,
+    sqlite::{SqliteConnectOptions, SqlitePool},
+};
+use std::{fs, path::PathBuf, str::FromStr};
+use storage::{
+    memory::{read_snapshot, write_snapshot},
+    MemoryStorage, SqliteStorage, Storage, StorageError, StoredIota, StoredItem,
+};
+use time::{Duration, OffsetDateTime};
+
+fn iota(pattern: &str, deletion: OffsetDateTime) -> StoredIota {
+    StoredIota {
+        pattern: pattern.to_owned(),
+        data: vec![10, 0, 0, 0],
+        password: vec![7; 255],
+        deletion,
+        iota_type: "hexcasting:list".to_owned(),
+        sanitized: true,
+    }
+}
+
+/// a snapshot path no other test uses
+fn snapshot_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("overmind-{}-{}.nbt", name, std::process::id()))
+}
+
+async fn put_and_get(storage: impl Storage) {
+    let later = OffsetDateTime::now_utc() + Duration::hours(1);
+    storage.put(iota("qaq", later)).await.unwrap();
+    assert_eq!(storage.get("qaq").await.unwrap(), vec![10, 0, 0, 0]);
+    assert!(matches!(
+        storage.put(iota("qaq", later)).await,
+        Err(StorageError::AlreadyExists(pattern)) if pattern == "qaq"
+    ));
+    assert!(matches!(
+        storage.get("aqaa").await,
+        Err(StorageError::NotFound(_))
+    ));
+}
+
+async fn delete_checks_password(storage: impl Storage) {
+    let later = OffsetDateTime::now_utc() + Duration::hours(1);
+    storage.put(iota("qaq", later)).await.unwrap();
+    assert!(matches!(
+        storage.delete("qaq", &[8; 255]).await,
+        Err(StorageError::WrongPassword(_))
+    ));
+    assert!(matches!(
+        storage.delete("aqaa", &[7; 255]).await,
+        Err(StorageError::NotFound(_))
+    ));
+    storage.delete("qaq", &[7; 255]).await.unwrap();
+    assert!(matches!(
+        storage.get("qaq").await,
+        Err(StorageError::NotFound(_))
+    ));
+}
+
+async fn prune_expired(storage: impl Storage) {
+    let now = OffsetDateTime::now_utc();
+    storage
+        .put(iota("qaq", now - Duration::seconds(1)))
+        .await
+        .unwrap();
+    storage
+        .put(iota("aqaa", now + Duration::hours(1)))
+        .await
+        .unwrap();
+    assert_eq!(storage.prune().await.unwrap(), 1);
+    assert!(matches!(
+        storage.get("qaq").await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(storage.get("aqaa").await.is_ok());
+    assert_eq!(storage.prune().await.unwrap(), 0);
+}
+
+async fn expired_iotas_are_gone(storage: impl Storage) {
+    let now = OffsetDateTime::now_utc();
+    storage
+        .put(iota("qaq", now - Duration::seconds(1)))
+        .await
+        .unwrap();
+    assert!(matches!(
+        storage
+            .refresh("qaq", &[7; 255], now + Duration::hours(1))
+            .await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(matches!(
+        storage.delete("qaq", &[8; 255]).await,
+        Err(StorageError::NotFound(_))
+    ));
+    for deletion in [None, Some(now + Duration::hours(1))] {
+        assert!(matches!(
+            storage
+                .replace("qaq", &[7; 255], vec![10, 0, 0, 2], "", false, deletion)
+                .await,
+            Err(StorageError::NotFound(_))
+        ));
+    }
+    assert!(matches!(
+        storage.stat("qaq").await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(matches!(
+        storage.get("qaq").await,
+        Err(StorageError::NotFound(_))
+    ));
+    assert!(storage.list_patterns("", "", 10).await.unwrap().is_empty());
+    //the pattern is free again without waiting for a prune
+    let mut fresh = iota("qaq", now + Duration::hours(1));
+    fresh.data = vec![10, 0, 0, 1];
+    storage.put(fresh).await.unwrap();
+    assert_eq!(storage.get("qaq").await.unwrap(), vec![10, 0, 0, 1]);
+    assert!(storage.stat("qaq").await.is_ok());
+    assert_eq!(storage.prune().await.unwrap(), 0);
+}
+
+async fn refresh_and_replace(storage: impl Storage) {
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let later = OffsetDateTime::from_unix_timestamp(now + 3600).unwrap();
+    let even_later = OffsetDateTime::from_unix_timestamp(now + 7200).unwrap();
+    storage.put(iota("qaq", later)).await.unwrap();
+    assert!(matches!(
+        storage.refresh("qaq", &[8; 255], even_later).await,
+        Err(StorageError::WrongPassword(_))
+    ));
+    storage.refresh("qaq", &[7; 255], even_later).await.unwrap();
+    assert_eq!(storage.stat("qaq").await.unwrap().deletion, even_later);
+
+    storage
+        .replace(
+            "qaq",
+            &[7; 255],
+            vec![10, 0, 0, 1],
+            "hexcasting:double",
+            false,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(storage.get("qaq").await.unwrap(), vec![10, 0, 0, 1]);
+    let stat = storage.stat("qaq").await.unwrap();
+    assert_eq!(stat.iota_type, "hexcasting:double");
+    assert!(!stat.sanitized);
+    assert_eq!(stat.deletion, even_later);
+    assert!(matches!(
+        storage
+            .replace("aqaa", &[7; 255], vec![], "", false, Some(later))
+            .await,
+        Err(StorageError::NotFound(_))
+    ));
+}
+
+async fn sqlite() -> SqliteStorage {
+    SqliteStorage::connect("sqlite::memory:").await.unwrap()
+}
+
+/// runs each of the tests above against a fresh store of every backend that runs in-process
+macro_rules! on_every_backend {
+    ($($test:ident),* $(,)?) => {
+        mod memory {
+            $(
+                #[tokio::test]
+                async fn $test() {
+                    super::$test(super::MemoryStorage::new()).await;
+                }
+            )*
+        }
+        mod sqlite {
+            $(
+                #[tokio::test]
+                async fn $test() {
+                    super::$test(super::sqlite().await).await;
+                }
+            )*
+        }
+    };
+}
+
+on_every_backend!(
+    put_and_get,
+    delete_checks_password,
+    prune_expired,
+    expired_iotas_are_gone,
+    refresh_and_replace,
+);
+
+#[tokio::test]
+async fn sqlite_adds_missing_columns() {
+    let path = std::env::temp_dir().join(format!("overmind-old-{}.db", std::process::id()));
+    let url = format!("sqlite://{}", path.display());
+    let deletion = OffsetDateTime::now_utc().unix_timestamp() + 3600;
+    let _ = fs::remove_file(&path);
+    {
+        let options = SqliteConnectOptions::from_str(&url)
+            .unwrap()
+            .create_if_missing(true);
+        let pool = SqlitePool::connect_with(options).await.unwrap();
+        //the table as it was before IotaType and Sanitized
+        query("CREATE TABLE HexDataStorage (Pattern VARCHAR(256) NOT NULL, Data BLOB NOT NULL, Password BLOB NOT NULL, Deletion INTEGER NOT NULL, PRIMARY KEY (Pattern));")
+            .execute(&pool)
+            .await
+            .unwrap();
+        query("INSERT INTO HexDataStorage VALUES ('qaq', X'0A000000', X'07', ?);")
+            .bind(deletion)
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool.close().await;
+    }
+    let storage = SqliteStorage::connect(&url).await.unwrap();
+    let stat = storage.stat("qaq").await.unwrap();
+    let data = storage.get("qaq").await.unwrap();
+    drop(storage);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(stat.iota_type, "");
+    assert!(!stat.sanitized);
+    assert_eq!(stat.deletion.unix_timestamp(), deletion);
+    assert_eq!(data, vec![10, 0, 0, 0]);
+}
+
+#[test]
+fn snapshot_round_trip() {
+    let path = snapshot_path("round-trip");
+    let deletion = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+    let iotas = vec![iota("qaq", deletion), iota("srv/aqaa", deletion)];
+    let items = vec![StoredItem {
+        pattern: "qwe".to_owned(),
+        item: vec![10, 0, 0, 0],
+        count: u64::MAX,
+        tags: vec!["minecraft:logs".to_owned(), "c:ingots".to_owned()],
+        deletion,
+    }];
+    write_snapshot(&path, &iotas, &items).unwrap();
+    let (read_iotas, read_items) = read_snapshot(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(read_iotas.len(), 2);
+    for (read, written) in read_iotas.iter().zip(&iotas) {
+        assert_eq!(read.pattern, written.pattern);
+        assert_eq!(read.data, written.data);
+        assert_eq!(read.password, written.password);
+        assert_eq!(read.deletion, written.deletion);
+        assert_eq!(read.iota_type, written.iota_type);
+        assert_eq!(read.sanitized, written.sanitized);
+    }
+    assert_eq!(read_items.len(), 1);
+    assert_eq!(read_items[0].pattern, "qwe");
+    assert_eq!(read_items[0].item, items[0].item);
+    assert_eq!(read_items[0].count, u64::MAX);
+    assert_eq!(read_items[0].tags, items[0].tags);
+    assert_eq!(read_items[0].deletion, deletion);
+}
+
+#[test]
+fn old_snapshots() {
+    //from before items and iota types were kept
+    let mut entry = NbtCompound::new();
+    entry.insert("Pattern", "qaq");
+    entry.insert("Data", vec![10u8, 0, 0, 0]);
+    entry.insert("Password", vec![7u8; 255]);
+    entry.insert("Deletion", 1_700_000_000i64);
+    let mut entries = NbtList::new();
+    entries.push(entry);
+    let mut root = NbtCompound::new();
+    root.insert("iotas", entries);
+    let mut bytes = vec![];
+    write_nbt(&mut bytes, None, &root, Flavor::GzCompressed).unwrap();
+    let path = snapshot_path("old");
+    fs::write(&path, bytes).unwrap();
+    let (iotas, items) = read_snapshot(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(iotas.len(), 1);
+    assert_eq!(iotas[0].pattern, "qaq");
+    assert_eq!(iotas[0].deletion.unix_timestamp(), 1_700_000_000);
+    assert_eq!(iotas[0].iota_type, "");
+    assert!(!iotas[0].sanitized);
+    assert!(items.is_empty());
+}